@@ -10,6 +10,7 @@ const {
   grantRole,
   revokeRole,
   updateOwner,
+  acceptOwnership,
   updateMinDelay,
 } = require("./timelockHelper");
 const { getTokenBalance, tokenMint, updateTokenAdmin } = require("./tokenHelper");
@@ -112,7 +113,7 @@ async function update_token_admin() {
 async function add_new_role_to_timelock(account, roleType){
     let target = process.env.timelock_contract_id;
     let fnName = "grant_role";
-    let data = `[{"address":"${account}"},{"u32":${roleType}}]`;
+    let data = `[{"address":"${account}"},{"symbol":"${roleType}"}]`;
     let currentTimeStamp = new Date().getTime() + "";
     let salt = Buffer.from(currentTimeStamp).toString("hex").padStart(64, "0");
     let predecessor = null;
@@ -145,7 +146,7 @@ async function add_new_role_to_timelock_self_managed(account, roleType){
 async function revoke_role_from_timelock(account, roleType) {
     let target = process.env.timelock_contract_id;
     let fnName = "revoke_role";
-    let data = `[{"address":"${account}"},{"u32":${roleType}}]`;
+    let data = `[{"address":"${account}"},{"symbol":"${roleType}"}]`;
     let currentTimeStamp = new Date().getTime() + "";
     let salt = Buffer.from(currentTimeStamp).toString("hex").padStart(64, "0");
     let predecessor = null;
@@ -220,6 +221,7 @@ async function update_timelock_owner() {
     await executeOperation(target, fnName, data, salt, predecessor);
     
     await getOperationTimeLock(operationId);
+    await acceptOwnership(newOwnerLabel, target);
 
     // rollback to original owner
     let originalOwnerLabel = users.timelockOwner;
@@ -240,6 +242,7 @@ async function update_timelock_owner() {
     await executeOperation(target, fnName, data2, salt2, predecessor);
 
     await getOperationTimeLock(operationId2);
+    await acceptOwnership(originalOwnerLabel, target);
 }
 
 async function update_timelock_owner_self_managed() {
@@ -247,11 +250,13 @@ async function update_timelock_owner_self_managed() {
     let newOwner = process.env[`${newOwnerLabel}_pubkey`];
 
     await updateOwner(newOwner);
+    await acceptOwnership(newOwnerLabel);
 
     // rollback to original owner
     let originalOwnerLabel = users.timelockOwner;
     let originalOwner = process.env[`${originalOwnerLabel}_pubkey`];
     await updateOwner(originalOwner, newOwnerLabel);
+    await acceptOwnership(originalOwnerLabel);
 }
 
 async function update_time_lock_min_delay() {
@@ -291,7 +296,7 @@ async function main() {
   console.log("Running update_token_admin ...")
   await update_token_admin();
   console.log("Running add_new_role_to_timelock ...")
-  let roleType = 3;
+  let roleType = "canceller";
   let account = generateNewKeypair();
   await add_new_role_to_timelock(account, roleType);
   console.log("Running add_new_role_to_timelock_self_managed ...")
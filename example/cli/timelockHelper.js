@@ -89,6 +89,13 @@ async function updateOwner(pubKey, timeLockOwner = users.timelockOwner) {
   console.log(`updateOwner ${pubKey} done`);
 }
 
+async function acceptOwnership(newOwner, contractId = timeLockSelfManagedContractId) {
+  await cmd(
+    `soroban contract invoke --id ${contractId} --source ${newOwner} --network ${networks.name} -- accept_ownership`
+  );
+  console.log(`acceptOwnership by ${newOwner} done`);
+}
+
 async function updateMinDelay(newMinDelay) {
   await cmd(
     `soroban contract invoke --id ${timeLockSelfManagedContractId} --source ${users.timelockOwner} --network ${networks.name} -- update_min_delay --delay ${newMinDelay}`
@@ -106,5 +113,6 @@ module.exports = {
   grantRole,
   revokeRole,
   updateOwner,
+  acceptOwnership,
   updateMinDelay,
 };
@@ -17,7 +17,7 @@ async function main() {
   await updateTimeLockMinDelay();
   console.log("Time lock grant role...");
   const roleAccount = generateNewKeypair();
-  const role = 3;
+  const role = "canceller";
   await addRoleToTimeLock(roleAccount, role);
   console.log("Time lock revoke role...");
   await revokeRoleFromTimeLock(roleAccount, role);
@@ -20,7 +20,7 @@ async function scheduleAndExecuteGrantRole(roleAccount, role) {
   let proposer = process.env[`${proposerLabel}_pubkey`];
   let target = process.env.timelock_contract_id;
   let fnName = "grant_role";
-  let data = [nativeToScVal(roleAccount, { type: "address" }), nativeToScVal(role, { type: "u32" })];
+  let data = [nativeToScVal(roleAccount, { type: "address" }), nativeToScVal(role, { type: "symbol" })];
   let currentTimeStamp = new Date().getTime() + "";
   let saltString = Buffer.from(currentTimeStamp)
     .toString("hex")
@@ -66,7 +66,7 @@ async function updateGrantRoleDirectly(roleAccount,role) {
   let timeLockOwnerLabel = users.timelockOwner;
   let timeLockOwnerSecret = process.env[`${timeLockOwnerLabel}_secret`];
   let timeLockContractId = process.env.timelock_self_managed_contract_id;
-  let params = [nativeToScVal(roleAccount, { type: "address" }), nativeToScVal(role, { type: "u32" })];
+  let params = [nativeToScVal(roleAccount, { type: "address" }), nativeToScVal(role, { type: "symbol" })];
 
   const keyPair = Keypair.fromSecret(timeLockOwnerSecret);
 
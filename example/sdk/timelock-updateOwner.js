@@ -56,11 +56,16 @@ async function scheduleAndExecuteUpdateOwner(newOwner, newOwnerSecret) {
       nativeToScVal(Address.fromString(executor), { type: "address" })
     );
     await executeOperation(executorKeyPair, timeLockContractId, executeParams);
+
+    // the new owner only takes over once it accepts the nomination
+    const newOwnerKeyPair = Keypair.fromSecret(newOwnerSecret);
+    await invokeContract(newOwnerKeyPair, timeLockContractId, "accept_ownership", []);
   }
 
   // rollback to the original owner
     let timeLockOwnerLabel = users.timelockOwner;
     let oldOwner = process.env[`${timeLockOwnerLabel}_pubkey`];
+    let oldOwnerSecret = process.env[`${timeLockOwnerLabel}_secret`];
     data = [nativeToScVal(oldOwner, { type: "address" })];
     const rollbackScheduleParams = [
       nativeToScVal(Address.fromString(proposer), { type: "address" }),
@@ -85,6 +90,9 @@ async function scheduleAndExecuteUpdateOwner(newOwner, newOwnerSecret) {
       nativeToScVal(Address.fromString(executor), { type: "address" })
     );
       await executeOperation(executorKeyPair, timeLockContractId, rollbackExecuteParams);
+
+      const oldOwnerKeyPair = Keypair.fromSecret(oldOwnerSecret);
+      await invokeContract(oldOwnerKeyPair, timeLockContractId, "accept_ownership", []);
     }
 }
 
@@ -97,10 +105,15 @@ async function updateOwnerDirectly(newOwner, newOwnerSecret) {
   let params = [nativeToScVal(newOwner, { type: "address" })];
   await invokeContract(keyPair, timeLockContractId, "update_owner", params);
 
+  // the nominee becomes the owner once it accepts the nomination
   const newOwnerKeyPair = Keypair.fromSecret(newOwnerSecret);
+  await invokeContract(newOwnerKeyPair, timeLockContractId, "accept_ownership", []);
+
+  // rollback to the original owner
   let oldOwner = process.env[`${timeLockOwnerLabel}_pubkey`];
   params = [nativeToScVal(oldOwner, { type: "address" })];
   await invokeContract(newOwnerKeyPair, timeLockContractId, "update_owner", params);
+  await invokeContract(keyPair, timeLockContractId, "accept_ownership", []);
 }
 
 async function main(newOwner, newOwnerSecret) {
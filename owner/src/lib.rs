@@ -7,6 +7,7 @@ pub mod owner {
     #[contracttype]
     enum OwnerKey {
         Owner,
+        PendingOwner,
     }
 
     #[derive(Copy, Clone)]
@@ -14,6 +15,67 @@ pub mod owner {
     #[repr(u32)]
     pub enum OwnerError {
         OnlyOwner = 1001,
+        NoPendingOwner = 1002,
+    }
+
+    /*
+     * Owner replaced by `set_owner`, `None` on the first assignment. This
+     * stands for `Option<Address>`, which soroban-sdk 20 cannot convert to an
+     * `ScVal` as a field of a contract type when `testutils` is enabled.
+     */
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[contracttype]
+    pub enum PreviousOwner {
+        None,
+        Account(Address),
+    }
+
+    impl PreviousOwner {
+        pub fn into_option(self) -> Option<Address> {
+            match self {
+                PreviousOwner::None => None,
+                PreviousOwner::Account(owner) => Some(owner),
+            }
+        }
+    }
+
+    impl From<Option<Address>> for PreviousOwner {
+        fn from(owner: Option<Address>) -> Self {
+            match owner {
+                Some(owner) => PreviousOwner::Account(owner),
+                None => PreviousOwner::None,
+            }
+        }
+    }
+
+    /*
+     * Published under the `OwnerSet` topic whenever the owner is set.
+     */
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[contracttype]
+    pub struct OwnerChangedEvent {
+        pub old: PreviousOwner,
+        pub new: Address,
+    }
+
+    /*
+     * Published under the `OwnershipTransferStarted` topic when `new` is
+     * nominated. `old` is the owner at the time of the nomination.
+     */
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[contracttype]
+    pub struct OwnershipTransferStartedEvent {
+        pub old: PreviousOwner,
+        pub new: Address,
+    }
+
+    /*
+     * Published under the `OwnershipRenounced` topic when the owner is removed.
+     */
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[contracttype]
+    pub struct OwnershipRenouncedEvent {
+        pub old: PreviousOwner,
     }
 
     pub fn has_owner(e: &Env) -> bool {
@@ -28,8 +90,73 @@ pub mod owner {
 
     pub fn set_owner(e: &Env, id: &Address) {
         let key = OwnerKey::Owner;
+        let old = PreviousOwner::from(get_owner(e));
         e.storage().instance().set(&key, id);
-        e.events().publish((Symbol::new(e, "OwnerSet"),), *&id);
+        e.events().publish(
+            (Symbol::new(e, "OwnerSet"),),
+            OwnerChangedEvent {
+                old,
+                new: id.clone(),
+            },
+        );
+    }
+
+    pub fn get_pending_owner(e: &Env) -> Option<Address> {
+        let key = OwnerKey::PendingOwner;
+        e.storage().instance().get(&key)
+    }
+
+    /*
+     * Nominates `new_owner`, the change only takes effect once the nominee
+     * calls `accept_ownership`. A new nomination replaces the previous one.
+     */
+    pub fn transfer_ownership(e: &Env, new_owner: &Address) {
+        let key = OwnerKey::PendingOwner;
+        e.storage().instance().set(&key, new_owner);
+        e.events().publish(
+            (Symbol::new(e, "OwnershipTransferStarted"),),
+            OwnershipTransferStartedEvent {
+                old: PreviousOwner::from(get_owner(e)),
+                new: new_owner.clone(),
+            },
+        );
+    }
+
+    pub fn accept_ownership(e: &Env) {
+        let key = OwnerKey::PendingOwner;
+        if let Some(new_owner) = get_pending_owner(e) {
+            new_owner.require_auth();
+            e.storage().instance().remove(&key);
+            set_owner(e, &new_owner);
+        } else {
+            panic_with_error!(e, OwnerError::NoPendingOwner);
+        }
+    }
+
+    pub fn cancel_ownership_transfer(e: &Env) {
+        let key = OwnerKey::PendingOwner;
+        if let Some(new_owner) = get_pending_owner(e) {
+            e.storage().instance().remove(&key);
+            e.events().publish(
+                (Symbol::new(e, "OwnershipTransferCancelled"),),
+                new_owner,
+            );
+        } else {
+            panic_with_error!(e, OwnerError::NoPendingOwner);
+        }
+    }
+
+    /*
+     * Removes the owner, and any pending nomination, for good.
+     */
+    pub fn renounce_ownership(e: &Env) {
+        let old = PreviousOwner::from(get_owner(e));
+        e.storage().instance().remove(&OwnerKey::Owner);
+        e.storage().instance().remove(&OwnerKey::PendingOwner);
+        e.events().publish(
+            (Symbol::new(e, "OwnershipRenounced"),),
+            OwnershipRenouncedEvent { old },
+        );
     }
 
     pub fn only_owner(e: &Env) {
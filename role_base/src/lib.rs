@@ -0,0 +1,234 @@
+#![no_std]
+
+pub mod role_base {
+    /*
+     * Role based access control with arbitrary `Symbol` roles.
+     *
+     * Each role can be given an admin role, whose holders may grant and revoke it.
+     * Grants and revocations record the `sender` that made them in their events.
+     * The module only keeps track of roles: authorization of the caller is left
+     * to the contract, except for `only_role`, `only_role_admin` and
+     * `renounce_role` which require the account's authorization.
+     *
+     * Role entries live in persistent storage. The functions that write or
+     * extend them take the contract's `RoleTtl`.
+     */
+    use soroban_sdk::{contracterror, contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+    #[derive(Clone)]
+    #[contracttype]
+    pub enum RoleKey {
+        Member(Symbol, Address),
+        Members(Symbol),
+        Admin(Symbol),
+    }
+
+    /*
+     * How long role entries are kept alive, in ledgers: they are extended to
+     * `extend_to` whenever less than `threshold` is left. `extend_to` is capped
+     * by the network's maximum entry TTL.
+     */
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct RoleTtl {
+        pub threshold: u32,
+        pub extend_to: u32,
+    }
+
+    #[derive(Copy, Clone)]
+    #[contracterror]
+    #[repr(u32)]
+    pub enum RoleError {
+        MissingRole = 1101,
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[contracttype]
+    pub struct RoleGrantedEvent {
+        pub role: Symbol,
+        pub account: Address,
+        pub sender: Address,
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[contracttype]
+    pub struct RoleRevokedEvent {
+        pub role: Symbol,
+        pub account: Address,
+        pub sender: Address,
+    }
+
+    pub fn grant_role(
+        e: &Env,
+        account: &Address,
+        role: &Symbol,
+        sender: &Address,
+        ttl: &RoleTtl,
+    ) -> bool {
+        let res = _set_role(e, &RoleKey::Member(role.clone(), account.clone()), ttl);
+        if res {
+            let mut members = get_role_members(e, role);
+            members.push_back(account.clone());
+            _set_members(e, role, &members, ttl);
+        }
+        e.events().publish(
+            (Symbol::new(e, "RoleGranted"), role.clone()),
+            RoleGrantedEvent {
+                role: role.clone(),
+                account: account.clone(),
+                sender: sender.clone(),
+            },
+        );
+
+        res
+    }
+
+    pub fn revoke_role(
+        e: &Env,
+        account: &Address,
+        role: &Symbol,
+        sender: &Address,
+        ttl: &RoleTtl,
+    ) -> bool {
+        let res = _unset_role(e, &RoleKey::Member(role.clone(), account.clone()));
+        if res {
+            let mut members = get_role_members(e, role);
+            if let Some(index) = members.first_index_of(account) {
+                members.remove(index);
+            }
+            _set_members(e, role, &members, ttl);
+        }
+        e.events().publish(
+            (Symbol::new(e, "RoleRevoked"), role.clone()),
+            RoleRevokedEvent {
+                role: role.clone(),
+                account: account.clone(),
+                sender: sender.clone(),
+            },
+        );
+
+        res
+    }
+
+    /*
+     * Gives up `role`. The account must hold the role and authorize the call.
+     */
+    pub fn renounce_role(e: &Env, account: &Address, role: &Symbol, ttl: &RoleTtl) {
+        only_role(e, account, role);
+        revoke_role(e, account, role, account, ttl);
+    }
+
+    pub fn has_role(e: &Env, account: &Address, role: &Symbol) -> bool {
+        is_role(e, &RoleKey::Member(role.clone(), account.clone()))
+    }
+
+    /*
+     * Requires `account` to hold `role` and to authorize the call.
+     */
+    pub fn only_role(e: &Env, account: &Address, role: &Symbol) {
+        if !has_role(e, account, role) {
+            panic_with_error!(e, RoleError::MissingRole);
+        }
+
+        account.require_auth();
+    }
+
+    /*
+     * Requires `account` to hold the admin role of `role` and to authorize the call.
+     */
+    pub fn only_role_admin(e: &Env, account: &Address, role: &Symbol, ttl: &RoleTtl) {
+        match get_role_admin(e, role, ttl) {
+            Some(admin_role) => only_role(e, account, &admin_role),
+            None => panic_with_error!(e, RoleError::MissingRole),
+        }
+    }
+
+    /*
+     * Returns the role whose holders may grant and revoke `role`, if any, and
+     * keeps the admin entry alive.
+     */
+    pub fn get_role_admin(e: &Env, role: &Symbol, ttl: &RoleTtl) -> Option<Symbol> {
+        let key = RoleKey::Admin(role.clone());
+        let admin_role = e.storage().persistent().get(&key);
+        if admin_role.is_some() {
+            _extend_ttl(e, &key, ttl);
+        }
+        admin_role
+    }
+
+    pub fn set_role_admin(e: &Env, role: &Symbol, admin_role: &Symbol, ttl: &RoleTtl) {
+        let key = RoleKey::Admin(role.clone());
+        let previous_admin_role = get_role_admin(e, role, ttl);
+        e.storage().persistent().set(&key, admin_role);
+        _extend_ttl(e, &key, ttl);
+        e.events().publish(
+            (Symbol::new(e, "RoleAdminChanged"), role.clone()),
+            (previous_admin_role, admin_role.clone()),
+        );
+    }
+
+    pub fn extend_role_ttl(e: &Env, account: &Address, role: &Symbol, ttl: &RoleTtl) {
+        let key = RoleKey::Member(role.clone(), account.clone());
+        if is_role(e, &key) {
+            _extend_ttl(e, &key, ttl);
+            _extend_ttl(e, &RoleKey::Members(role.clone()), ttl);
+        }
+    }
+
+    /*
+     * Returns the accounts holding `role`, in the order they were granted it.
+     */
+    pub fn get_role_members(e: &Env, role: &Symbol) -> Vec<Address> {
+        e.storage()
+            .persistent()
+            .get(&RoleKey::Members(role.clone()))
+            .unwrap_or(Vec::new(e))
+    }
+
+    pub fn get_role_member_count(e: &Env, role: &Symbol) -> u32 {
+        get_role_members(e, role).len()
+    }
+
+    pub fn get_role_member(e: &Env, role: &Symbol, index: u32) -> Option<Address> {
+        get_role_members(e, role).get(index)
+    }
+
+    fn is_role(e: &Env, key: &RoleKey) -> bool {
+        e.storage().persistent().has(key)
+    }
+
+    fn _set_role(e: &Env, key: &RoleKey, ttl: &RoleTtl) -> bool {
+        if !is_role(e, key) {
+            e.storage().persistent().set(key, &true);
+            _extend_ttl(e, key, ttl);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn _unset_role(e: &Env, key: &RoleKey) -> bool {
+        if is_role(e, key) {
+            e.storage().persistent().remove(key);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn _set_members(e: &Env, role: &Symbol, members: &Vec<Address>, ttl: &RoleTtl) {
+        let key = RoleKey::Members(role.clone());
+        if members.is_empty() {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, members);
+            _extend_ttl(e, &key, ttl);
+        }
+    }
+
+    fn _extend_ttl(e: &Env, key: &RoleKey, ttl: &RoleTtl) {
+        let extend_to = ttl.extend_to.min(e.storage().max_ttl() - 1);
+        e.storage()
+            .persistent()
+            .extend_ttl(key, ttl.threshold.min(extend_to), extend_to);
+    }
+}
@@ -1,5 +1,6 @@
 use soroban_sdk::testutils::{Ledger, LedgerInfo};
-use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Val, Vec};
+use soroban_sdk::xdr::{ContractDataDurability, LedgerKey, ScVal};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
 use std::time::{SystemTime, UNIX_EPOCH};
 use time_lock::test::TimeLockControllerClient;
 
@@ -12,22 +13,29 @@ pub struct Context {
     pub owner: Option<Address>,
 }
 
+/*
+ * Independent encoding of a single call operation id, to cross-check
+ * `hash_operation`: a versioned domain prefix, the timelock address and the
+ * network id, followed by the call parameters. Tests should otherwise get ids
+ * from the `hash_operation*` views.
+ */
 pub fn hash_call_data(
     env: &Env,
+    time_lock: &Address,
     target: &Address,
     fn_name: &Symbol,
     data: &Vec<Val>,
     predecessor: &Option<BytesN<32>>,
     salt: &BytesN<32>,
 ) -> BytesN<32> {
-    let mut calldata = Bytes::new(&env);
-    calldata.append(&target.clone().to_xdr(&env));
-    calldata.append(&fn_name.clone().to_xdr(&env));
-    calldata.append(&data.clone().to_xdr(&env));
-    if let Some(predecessor) = predecessor {
-        calldata.append(&predecessor.clone().to_xdr(&env));
-    }
-    calldata.append(&salt.clone().to_xdr(&env));
+    let mut calldata = Bytes::from_slice(env, b"TimeLockOperation/v1");
+    calldata.append(&time_lock.clone().to_xdr(env));
+    calldata.append(&env.ledger().network_id().to_xdr(env));
+    calldata.append(&target.clone().to_xdr(env));
+    calldata.append(&fn_name.clone().to_xdr(env));
+    calldata.append(&data.clone().to_xdr(env));
+    calldata.append(&predecessor.clone().to_xdr(env));
+    calldata.append(&salt.clone().to_xdr(env));
     env.crypto().sha256(&calldata)
 }
 
@@ -39,10 +47,14 @@ pub fn current_timestamp() -> u64 {
 }
 
 pub fn set_env_timestamp(env: &Env, timestamp: u64) {
+    set_env_ledger(env, timestamp, 0)
+}
+
+pub fn set_env_ledger(env: &Env, timestamp: u64, sequence_number: u32) {
     env.ledger().set(LedgerInfo {
         timestamp,
         protocol_version: 20,
-        sequence_number: 0,
+        sequence_number,
         network_id: Default::default(),
         base_reserve: 10,
         min_temp_entry_ttl: 10,
@@ -50,3 +62,22 @@ pub fn set_env_timestamp(env: &Env, timestamp: u64) {
         max_entry_ttl: 2_000_000,
     })
 }
+
+/*
+ * Returns the ledger until which the persistent entry stored under `key` is
+ * alive, `None` if there is no such entry.
+ */
+pub fn persistent_live_until(env: &Env, key: impl IntoVal<Env, Val>) -> Option<u32> {
+    let key = ScVal::try_from_val(env, &key.into_val(env)).unwrap();
+    env.to_ledger_snapshot()
+        .ledger_entries
+        .iter()
+        .find_map(|(ledger_key, (_, live_until))| match ledger_key.as_ref() {
+            LedgerKey::ContractData(data)
+                if data.key == key && data.durability == ContractDataDurability::Persistent =>
+            {
+                *live_until
+            }
+            _ => None,
+        })
+}
@@ -85,6 +85,11 @@ impl IncrementContract {
         count
     }
 
+    /// Increments the counter of another instance, whose owner must have authorized the call.
+    pub fn increment_other(env: Env, other: Address, num: u32) -> u32 {
+        IncrementContractClient::new(&env, &other).increment(&num)
+    }
+
     pub fn increment_owner(env: Env, num: u32) -> u32 {
         owner::only_owner(&env);
         IncrementContract::increment(env, num)
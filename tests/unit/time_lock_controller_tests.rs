@@ -6,8 +6,10 @@ use soroban_sdk::testutils::{
 };
 use soroban_sdk::{vec, Address, Env, Error};
 use time_lock::test::{
-    CallExecutedEvent, CallScheduledEvent, RoleLabel, TimeLockController, TimeLockControllerClient,
-    TimeLockError, OwnerError
+    CallExecutedEvent, CallScheduledEvent, CancelledEvent, ExecutionFailure, MinDelayChangedEvent,
+    Operation, OperationExecutor, OperationState, OperationStatus, OwnerChangedEvent, OwnerError,
+    OwnershipRenouncedEvent, OwnershipTransferStartedEvent, PreviousOwner, RoleGrantedEvent,
+    RoleLabel, RoleRevokedEvent, TimeLockController, TimeLockControllerClient, TimeLockError,
 };
 use time_lock_example_contract::test::{IncrementContract, IncrementContractClient};
 use time_lock_tests_common::{current_timestamp, hash_call_data, set_env_timestamp, Context};
@@ -35,6 +37,8 @@ fn setup(is_self_managed: bool) -> Context {
         &MIN_DELAY,
         &vec![&env, proposer.clone()],
         &vec![&env, executor.clone()],
+        &None,
+        &None,
         &owner,
     );
     Context {
@@ -62,9 +66,9 @@ mod initialize {
         } = setup(true);
 
         assert_eq!(client.get_min_delay(), MIN_DELAY);
-        assert_eq!(client.has_role(&proposer, &RoleLabel::Proposer), true);
-        assert_eq!(client.has_role(&proposer, &RoleLabel::Canceller), true);
-        assert_eq!(client.has_role(&executor, &RoleLabel::Executor), true);
+        assert_eq!(client.has_role(&proposer, &RoleLabel::Proposer.to_symbol()), true);
+        assert_eq!(client.has_role(&proposer, &RoleLabel::Canceller.to_symbol()), true);
+        assert_eq!(client.has_role(&executor, &RoleLabel::Executor.to_symbol()), true);
 
         let all_actual_events = env.events().all();
         assert_eq!(all_actual_events.len(), 5);
@@ -76,27 +80,50 @@ mod initialize {
                 (
                     contract_id.clone(),
                     (Symbol::new(&env, "MinDelayUpdated"),).into_val(&env),
-                    MIN_DELAY.into_val(&env)
+                    MinDelayChangedEvent {
+                        old: 0,
+                        new: MIN_DELAY,
+                    }
+                    .into_val(&env)
                 ),
                 (
                     contract_id.clone(),
                     (Symbol::new(&env, "OwnerSet"),).into_val(&env),
-                    owner.clone().into_val(&env)
+                    OwnerChangedEvent {
+                        old: PreviousOwner::None,
+                        new: owner.clone().unwrap(),
+                    }
+                    .into_val(&env)
                 ),
                 (
                     contract_id.clone(),
-                    (Symbol::new(&env, "RoleGranted"), 1_u32).into_val(&env),
-                    proposer.clone().into_val(&env)
+                    (Symbol::new(&env, "RoleGranted"), RoleLabel::Proposer.to_symbol()).into_val(&env),
+                    RoleGrantedEvent {
+                        role: RoleLabel::Proposer.to_symbol(),
+                        account: proposer.clone(),
+                        sender: contract_id.clone(),
+                    }
+                    .into_val(&env)
                 ),
                 (
                     contract_id.clone(),
-                    (Symbol::new(&env, "RoleGranted"), 3_u32).into_val(&env),
-                    proposer.clone().into_val(&env)
+                    (Symbol::new(&env, "RoleGranted"), RoleLabel::Canceller.to_symbol()).into_val(&env),
+                    RoleGrantedEvent {
+                        role: RoleLabel::Canceller.to_symbol(),
+                        account: proposer.clone(),
+                        sender: contract_id.clone(),
+                    }
+                    .into_val(&env)
                 ),
                 (
                     contract_id.clone(),
-                    (Symbol::new(&env, "RoleGranted"), 2_u32).into_val(&env),
-                    executor.clone().into_val(&env)
+                    (Symbol::new(&env, "RoleGranted"), RoleLabel::Executor.to_symbol()).into_val(&env),
+                    RoleGrantedEvent {
+                        role: RoleLabel::Executor.to_symbol(),
+                        account: executor.clone(),
+                        sender: contract_id.clone(),
+                    }
+                    .into_val(&env)
                 ),
             ]
         );
@@ -114,6 +141,8 @@ mod initialize {
             &MIN_DELAY,
             &vec![&env],
             &vec![&env, executor.clone()],
+            &None,
+            &None,
             &Some(Address::generate(&env)),
         );
     }
@@ -130,6 +159,8 @@ mod initialize {
             &MIN_DELAY,
             &vec![&env, proposer.clone()],
             &vec![&env],
+            &None,
+            &None,
             &Some(Address::generate(&env)),
         );
     }
@@ -144,6 +175,106 @@ mod initialize {
             &MIN_DELAY,
             &vec![&env],
             &vec![&env],
+            &None,
+            &None,
+            &Some(Address::generate(&env)),
+        );
+    }
+
+    #[test]
+    fn is_ok_with_cancellers_and_guardians() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TimeLockController);
+        let client = TimeLockControllerClient::new(&env, &contract_id);
+
+        let proposer = Address::generate(&env);
+        let executor = Address::generate(&env);
+        let canceller = Address::generate(&env);
+        let guardian = Address::generate(&env);
+
+        client.initialize(
+            &MIN_DELAY,
+            &vec![&env, proposer.clone()],
+            &vec![&env, executor.clone()],
+            &Some(vec![&env, canceller.clone()]),
+            &Some(vec![&env, guardian.clone()]),
+            &Some(Address::generate(&env)),
+        );
+
+        assert!(client.has_role(&proposer, &RoleLabel::Proposer.to_symbol()));
+        assert!(!client.has_role(&proposer, &RoleLabel::Canceller.to_symbol()));
+        assert!(client.has_role(&canceller, &RoleLabel::Canceller.to_symbol()));
+        assert!(!client.has_role(&canceller, &RoleLabel::Proposer.to_symbol()));
+        assert!(client.has_role(&guardian, &RoleLabel::Guardian.to_symbol()));
+        assert!(client.has_role(&executor, &RoleLabel::Executor.to_symbol()));
+    }
+
+    #[test]
+    fn is_ok_with_empty_cancellers() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TimeLockController);
+        let client = TimeLockControllerClient::new(&env, &contract_id);
+
+        let proposer = Address::generate(&env);
+
+        client.initialize(
+            &MIN_DELAY,
+            &vec![&env, proposer.clone()],
+            &vec![&env],
+            &Some(vec![&env]),
+            &None,
+            &Some(Address::generate(&env)),
+        );
+
+        assert!(client.has_role(&proposer, &RoleLabel::Proposer.to_symbol()));
+        assert_eq!(client.get_role_member_count(&RoleLabel::Canceller.to_symbol()), 0);
+        assert_eq!(client.get_role_member_count(&RoleLabel::Guardian.to_symbol()), 0);
+    }
+
+    #[test]
+    fn duplicate_accounts_should_panic() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TimeLockController);
+        let client = TimeLockControllerClient::new(&env, &contract_id);
+
+        let proposer = Address::generate(&env);
+        let executor = Address::generate(&env);
+        let guardian = Address::generate(&env);
+
+        assert_eq!(
+            client.try_initialize(
+                &MIN_DELAY,
+                &vec![&env, proposer.clone(), proposer.clone()],
+                &vec![&env, executor.clone()],
+                &None,
+                &None,
+                &Some(Address::generate(&env)),
+            ),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidParams as u32
+            )))
+        );
+
+        assert_eq!(
+            client.try_initialize(
+                &MIN_DELAY,
+                &vec![&env, proposer.clone()],
+                &vec![&env, executor.clone()],
+                &None,
+                &Some(vec![&env, guardian.clone(), guardian.clone()]),
+                &Some(Address::generate(&env)),
+            ),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidParams as u32
+            )))
+        );
+
+        client.initialize(
+            &MIN_DELAY,
+            &vec![&env, proposer.clone()],
+            &vec![&env, proposer.clone()],
+            &Some(vec![&env, proposer.clone()]),
+            &Some(vec![&env, proposer.clone()]),
             &Some(Address::generate(&env)),
         );
     }
@@ -161,12 +292,16 @@ mod initialize {
             &MIN_DELAY,
             &vec![&env, proposer.clone()],
             &vec![&env, executor.clone()],
+            &None,
+            &None,
             &Some(Address::generate(&env)),
         );
         client.initialize(
             &MIN_DELAY,
             &vec![&env, proposer.clone()],
             &vec![&env, executor.clone()],
+            &None,
+            &None,
             &Some(Address::generate(&env)),
         );
     }
@@ -185,12 +320,16 @@ mod initialize {
             &vec![&env, proposer.clone()],
             &vec![&env, executor.clone()],
             &None,
+            &None,
+            &None,
         );
         client.initialize(
             &MIN_DELAY,
             &vec![&env, proposer.clone()],
             &vec![&env, executor.clone()],
             &None,
+            &None,
+            &None,
         );
     }
 
@@ -210,6 +349,8 @@ mod initialize {
                 &delay,
                 &vec![&env, proposer.clone()],
                 &vec![&env, executor.clone()],
+                &None,
+                &None,
                 &Some(Address::generate(&env)),
             ),
             Err(Ok(Error::from_contract_error(
@@ -283,7 +424,7 @@ mod schedule {
         );
 
         let expected_operation_id =
-            hash_call_data(&env, &target, &fn_name, &data, &predecessor, &salt);
+            client.hash_operation(&target, &fn_name, &data, &salt, &predecessor);
         assert_eq!(operation_id, expected_operation_id);
 
         let actual_lock_time = client.get_schedule_lock_time(&operation_id);
@@ -302,15 +443,86 @@ mod schedule {
                     (Symbol::new(&env, "CallScheduled"),).into_val(&env),
                     CallScheduledEvent {
                         opt_id: operation_id.clone(),
+                        index: 0,
                         target: target.clone(),
                         fn_name:fn_name.clone(),
                         data: data.clone(),
-                        predecessor: BytesN::from_array(&env, &[0_u8; 32]),
-                        delay: delay
+                        predecessors: vec![&env],
+                        delay,
+                        description: String::from_str(&env, ""),
+                    }.into_val(&env)
+                )
+            ]
+        }
+    }
+
+    #[test]
+    fn with_description_is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+        let delay = MIN_DELAY;
+        let description = String::from_str(&env, "CHG-1234: mint the Q3 allocation");
+
+        let operation_id = client.schedule_with_description(
+            &proposer,
+            &target,
+            &fn_name,
+            &data,
+            &salt,
+            &None,
+            &delay,
+            &description,
+        );
+
+        assert_eq!(
+            operation_id,
+            client.hash_operation(&target, &fn_name, &data, &salt, &None)
+        );
+        assert_eq!(
+            client.get_operation(&operation_id).unwrap().description,
+            description
+        );
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "CallScheduled"),).into_val(&env),
+                    CallScheduledEvent {
+                        opt_id: operation_id.clone(),
+                        index: 0,
+                        target: target.clone(),
+                        fn_name: fn_name.clone(),
+                        data: data.clone(),
+                        predecessors: vec![&env],
+                        delay,
+                        description: description.clone(),
                     }.into_val(&env)
                 )
             ]
         }
+
+        assert_eq!(
+            client.try_schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::AlreadyExists as u32
+            )))
+        );
     }
 
     #[test]
@@ -445,7 +657,7 @@ mod schedule {
 
 mod execute {
     use super::*;
-    use soroban_sdk::{vec, Address, BytesN, IntoVal, Symbol};
+    use soroban_sdk::{vec, Address, BytesN, FromVal, IntoVal, Symbol};
     #[test]
     fn work_without_predecessor() {
         let Context {
@@ -481,7 +693,8 @@ mod execute {
 
         set_env_timestamp(&env, current_timestamp());
 
-        client.execute(&executor, &target, &fn_name, &data, &salt, &predecessor);
+        let result = client.execute(&executor, &target, &fn_name, &data, &salt, &predecessor);
+        assert_eq!(u32::from_val(&env, &result), 1000);
 
         assert_eq!(
             env.auths(),
@@ -523,9 +736,11 @@ mod execute {
                     (Symbol::new(&env, "CallExecuted"),).into_val(&env),
                     CallExecutedEvent {
                         opt_id: operation_id.clone(),
+                        index: 0,
                         target: target.clone(),
                         fn_name:fn_name.clone(),
                         data: data.clone(),
+                        result: vec![&env, 1000_u32.into_val(&env)],
                     }.into_val(&env)
                 )
             ]
@@ -624,10 +839,10 @@ mod execute {
         set_env_timestamp(&env, current_timestamp());
 
         assert_eq!(
-            client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor),
-            Err(Ok(Error::from_contract_error(
+            client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor).unwrap_err(),
+            Ok(Error::from_contract_error(
                 TimeLockError::ExecuteFailed as u32
-            )))
+            ))
         );
     }
 
@@ -665,10 +880,10 @@ mod execute {
         set_env_timestamp(&env, current_timestamp());
 
         assert_eq!(
-            client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor),
-            Err(Ok(Error::from_contract_error(
+            client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor).unwrap_err(),
+            Ok(Error::from_contract_error(
                 TimeLockError::ExecuteFailed as u32
-            )))
+            ))
         );
     }
 
@@ -706,10 +921,10 @@ mod execute {
         set_env_timestamp(&env, current_timestamp());
 
         assert_eq!(
-            client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor),
-            Err(Ok(Error::from_contract_error(
+            client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor).unwrap_err(),
+            Ok(Error::from_contract_error(
                 TimeLockError::ExecuteFailed as u32
-            )))
+            ))
         );
     }
 
@@ -894,68 +1109,41 @@ mod execute {
     }
 }
 
-mod cancel {
+mod attempt_execute {
     use super::*;
-    use soroban_sdk::{symbol_short, vec, Address, BytesN, IntoVal, Symbol};
+    use soroban_sdk::{vec, BytesN, IntoVal, Symbol};
+    use time_lock::test::CallFailedEvent;
 
     #[test]
-    fn is_ok() {
+    fn contract_error_is_recorded() {
         let Context {
             env,
             contract: contract_id,
             time_lock: client,
             proposer,
-            executor: _,
+            executor,
             owner: _,
         } = setup(true);
 
-        let target = Address::generate(&env);
-        let fn_name = symbol_short!("hello");
-        let data = vec![&env, symbol_short!("lily").to_val()];
-        let salt = BytesN::random(&env);
-        let delay: u64 = MIN_DELAY + 10;
-        let predecessor: Option<BytesN<32>> = None;
-        let operation_id = client.schedule(
-            &proposer,
-            &target,
-            &fn_name,
-            &data,
-            &salt,
-            &predecessor,
-            &delay,
-        );
-
-        let ledger_time = env.ledger().timestamp();
-        let expect_lock_time = ledger_time + delay;
-
-        let actual_lock_time = client.get_schedule_lock_time(&operation_id);
-        assert_eq!(actual_lock_time, expect_lock_time);
-
-        set_env_timestamp(&env, current_timestamp());
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract_id);
 
-        client.cancel(&proposer, &operation_id);
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment_return_error");
+        let data = (1000_u32,).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
 
-        assert_eq!(
-            env.auths(),
-            std::vec![(
-                proposer.clone(),
-                AuthorizedInvocation {
-                    function: AuthorizedFunction::Contract((
-                        contract_id.clone(),
-                        Symbol::new(&env, "cancel"),
-                        (&proposer, operation_id.clone(),).into_val(&env)
-                    )),
-                    sub_invocations: std::vec![]
-                }
-            )]
-        );
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+        set_env_timestamp(&env, delay);
 
-        assert_eq!(client.get_schedule_lock_time(&operation_id), 0);
-        assert_eq!(client.get_schedule_lock_time(&operation_id), 0);
+        let res = client.attempt_execute(&executor, &target, &fn_name, &data, &salt, &None);
+        assert!(!res);
 
         let actual_events = env.events().all();
         let event_len = actual_events.len();
-        assert_eq!(event_len, 7);
 
         assert_eq! {
             actual_events.slice(event_len - 1..),
@@ -963,135 +1151,386 @@ mod cancel {
                 &env,
                 (
                     contract_id.clone(),
-                    (Symbol::new(&env, "OperationCancelled"),).into_val(&env),
-                    operation_id.clone().into_val(&env)
+                    (Symbol::new(&env, "CallFailed"),).into_val(&env),
+                    CallFailedEvent {
+                        opt_id: operation_id.clone(),
+                        index: 0,
+                        target: target.clone(),
+                        fn_name: fn_name.clone(),
+                        failure: ExecutionFailure::Contract(1),
+                    }
+                    .into_val(&env)
                 )
             ]
         }
+
+        let operation = client.get_operation(&operation_id).unwrap();
+        assert_eq!(operation.last_failure, ExecutionFailure::Contract(1));
+        assert_eq!(operation.status, OperationStatus::Pending);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Ready);
     }
 
     #[test]
-    fn operation_waiting_is_ok() {
+    fn panic_is_recorded() {
         let Context {
             env,
             contract: contract_id,
             time_lock: client,
             proposer,
-            executor: _,
+            executor,
             owner: _,
         } = setup(true);
 
-        set_env_timestamp(&env, current_timestamp());
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract_id);
 
-        let target = Address::generate(&env);
-        let fn_name = symbol_short!("hello");
-        let data = vec![&env, symbol_short!("lily").to_val()];
-        let salt = BytesN::random(&env);
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment_with_panic");
+        let data = (1000_u32,).into_val(&env);
         let delay: u64 = MIN_DELAY + 10;
-        let predecessor: Option<BytesN<32>> = None;
+        let salt = BytesN::random(&env);
 
-        let operation_id = client.schedule(
-            &proposer,
-            &target,
-            &fn_name,
-            &data,
-            &salt,
-            &predecessor,
-            &delay,
-        );
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+        set_env_timestamp(&env, delay);
 
-        let ledger_time = env.ledger().timestamp();
-        let expect_lock_time = ledger_time + delay;
+        let res = client.attempt_execute(&executor, &target, &fn_name, &data, &salt, &None);
+        assert!(!res);
 
-        let actual_lock_time = client.get_schedule_lock_time(&operation_id);
-        assert_eq!(actual_lock_time, expect_lock_time);
+        let operation = client.get_operation(&operation_id).unwrap();
+        assert!(matches!(operation.last_failure, ExecutionFailure::Host(_, _)));
+    }
 
-        client.cancel(&proposer, &operation_id);
+    #[test]
+    fn success_is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
 
-        assert_eq!(
-            env.auths(),
-            std::vec![(
-                proposer.clone(),
-                AuthorizedInvocation {
-                    function: AuthorizedFunction::Contract((
-                        contract_id.clone(),
-                        Symbol::new(&env, "cancel"),
-                        (&proposer, operation_id.clone(),).into_val(&env)
-                    )),
-                    sub_invocations: std::vec![]
-                }
-            )]
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract_id);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (10_u32,).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        assert_eq!(
+            client.try_attempt_execute(&executor, &target, &fn_name, &data, &salt, &None),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::TimeNotReady as u32
+            )))
         );
 
-        assert_eq!(client.get_schedule_lock_time(&operation_id), 0);
-        assert_eq!(client.get_schedule_lock_time(&operation_id), 0);
+        set_env_timestamp(&env, delay);
 
-        let actual_events = env.events().all();
-        let event_len = actual_events.len();
-        assert_eq!(event_len, 7);
+        let res = client.attempt_execute(&executor, &target, &fn_name, &data, &salt, &None);
+        assert!(res);
+        assert_eq!(example_client.get_count(), 10);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Executed);
+    }
+}
 
-        assert_eq! {
-            actual_events.slice(event_len - 1..),
-            vec![
-                &env,
-                (
-                    contract_id.clone(),
-                    (Symbol::new(&env, "OperationCancelled"),).into_val(&env),
-                    operation_id.clone().into_val(&env)
+mod execute_with_auth {
+    use super::*;
+    use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+    use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+    use soroban_sdk::{vec, BytesN, IntoVal, Symbol, Val, Vec};
+
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let counter_id = env.register_contract(None, IncrementContract);
+        let counter_client = IncrementContractClient::new(&env, &counter_id);
+        counter_client.initialize(&contract_id);
+        let caller_id = env.register_contract(None, IncrementContract);
+
+        let target = caller_id.clone();
+        let fn_name = Symbol::new(&env, "increment_other");
+        let data: Vec<Val> = (counter_id.clone(), 7_u32).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+        let predecessor: Option<BytesN<32>> = None;
+        let auth_entries = vec![
+            &env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: counter_id.clone(),
+                    fn_name: Symbol::new(&env, "increment"),
+                    args: (7_u32,).into_val(&env),
+                },
+                sub_invocations: vec![&env],
+            }),
+        ];
+
+        let operation_id = client.schedule_with_auth(
+            &proposer,
+            &target,
+            &fn_name,
+            &data,
+            &salt,
+            &predecessor,
+            &delay,
+            &auth_entries,
+        );
+        assert_eq!(
+            operation_id,
+            client.hash_operation_with_auth(&target, &fn_name, &data, &salt, &predecessor, &auth_entries)
+        );
+        assert_ne!(
+            operation_id,
+            client.hash_operation(&target, &fn_name, &data, &salt, &predecessor)
+        );
+        assert_eq!(
+            client.hash_operation_with_auth(&target, &fn_name, &data, &salt, &predecessor, &Vec::new(&env)),
+            client.hash_operation(&target, &fn_name, &data, &salt, &predecessor)
+        );
+
+        set_env_timestamp(&env, delay);
+
+        env.mock_auths(&[MockAuth {
+            address: &executor,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "execute_with_auth",
+                args: (
+                    &executor,
+                    &target,
+                    &fn_name,
+                    data.clone(),
+                    salt.clone(),
+                    predecessor.clone(),
+                    auth_entries.clone(),
                 )
-            ]
-        }
+                    .into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        client.execute_with_auth(&executor, &target, &fn_name, &data, &salt, &predecessor, &auth_entries);
+
+        assert_eq!(counter_client.get_count(), 7);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Executed);
     }
 
     #[test]
-    #[should_panic = "Error(Contract, #9)"]
-    fn not_canceler_should_panic() {
+    fn without_auth_entries_should_fail() {
         let Context {
             env,
-            contract: _,
+            contract: contract_id,
             time_lock: client,
             proposer,
-            executor: _,
+            executor,
             owner: _,
         } = setup(true);
 
-        let target = Address::generate(&env);
-        let fn_name = symbol_short!("hello");
-        let data = vec![&env, symbol_short!("lily").to_val()];
-        let salt = BytesN::random(&env);
+        let counter_id = env.register_contract(None, IncrementContract);
+        let counter_client = IncrementContractClient::new(&env, &counter_id);
+        counter_client.initialize(&contract_id);
+        let caller_id = env.register_contract(None, IncrementContract);
+
+        let target = caller_id.clone();
+        let fn_name = Symbol::new(&env, "increment_other");
+        let data = (counter_id.clone(), 7_u32).into_val(&env);
         let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+        let predecessor: Option<BytesN<32>> = None;
 
-        let operation_id =
-            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &predecessor, &delay);
+        set_env_timestamp(&env, delay);
+
+        env.mock_auths(&[MockAuth {
+            address: &executor,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "execute",
+                args: (
+                    &executor,
+                    &target,
+                    &fn_name,
+                    data.clone(),
+                    salt.clone(),
+                    predecessor.clone(),
+                )
+                    .into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert_eq!(
+            client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor).unwrap_err(),
+            Ok(Error::from_contract_error(
+                TimeLockError::ExecuteFailed as u32
+            ))
+        );
+    }
+}
 
-        set_env_timestamp(&env, current_timestamp());
+mod multiple_predecessors {
+    use super::*;
+    use soroban_sdk::{vec, BytesN, IntoVal, Symbol, Val, Vec};
 
-        let caller = Address::generate(&env);
-        client.cancel(&caller, &operation_id);
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract_id);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data: Vec<Val> = (1_u32,).into_val(&env);
+        let salts = [BytesN::random(&env), BytesN::random(&env), BytesN::random(&env)];
+
+        let first_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salts[0], &None, &MIN_DELAY);
+        let second_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salts[1], &None, &MIN_DELAY);
+        let predecessors = vec![&env, first_id.clone(), second_id.clone()];
+        let operation_id = client.schedule_with_predecessors(
+            &proposer,
+            &target,
+            &fn_name,
+            &data,
+            &salts[2],
+            &predecessors,
+            &MIN_DELAY,
+        );
+
+        assert_eq!(
+            client.hash_operation_with_predecessors(&target, &fn_name, &data, &salts[2], &predecessors),
+            operation_id
+        );
+        let operation = client.get_operation(&operation_id).unwrap();
+        assert_eq!(operation.predecessors, predecessors);
+
+        set_env_timestamp(&env, MIN_DELAY);
+
+        client.execute(&executor, &target, &fn_name, &data, &salts[0], &None);
+        assert_eq!(
+            client
+                .try_execute_with_predecessors(
+                    &executor,
+                    &target,
+                    &fn_name,
+                    &data,
+                    &salts[2],
+                    &predecessors
+                )
+                .unwrap_err(),
+            Ok(Error::from_contract_error(
+                TimeLockError::PredecessorNotDone as u32
+            ))
+        );
+
+        client.execute(&executor, &target, &fn_name, &data, &salts[1], &None);
+        client.execute_with_predecessors(
+            &executor,
+            &target,
+            &fn_name,
+            &data,
+            &salts[2],
+            &predecessors,
+        );
+
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Executed);
+        assert_eq!(example_client.get_count(), 3);
     }
+
     #[test]
-    #[should_panic = "Error(Contract, #8)"]
-    fn unset_operation_should_panic() {
+    fn single_predecessor_keeps_id() {
         let Context {
             env,
             contract: _,
             time_lock: client,
-            proposer,
+            proposer: _,
             executor: _,
             owner: _,
         } = setup(true);
 
-        let operation_id = BytesN::random(&env);
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data: Vec<Val> = (1_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+        let first = BytesN::random(&env);
+        let second = BytesN::random(&env);
 
-        client.cancel(&proposer, &operation_id);
+        assert_eq!(
+            client.hash_operation_with_predecessors(&target, &fn_name, &data, &salt, &vec![&env]),
+            client.hash_operation(&target, &fn_name, &data, &salt, &None)
+        );
+        assert_eq!(
+            client.hash_operation_with_predecessors(
+                &target,
+                &fn_name,
+                &data,
+                &salt,
+                &vec![&env, first.clone()]
+            ),
+            client.hash_operation(&target, &fn_name, &data, &salt, &Some(first.clone()))
+        );
+        assert_ne!(
+            client.hash_operation_with_predecessors(
+                &target,
+                &fn_name,
+                &data,
+                &salt,
+                &vec![&env, first.clone(), second.clone()]
+            ),
+            client.hash_operation_with_predecessors(
+                &target,
+                &fn_name,
+                &data,
+                &salt,
+                &vec![&env, second.clone(), first.clone()]
+            )
+        );
+
+        let targets = vec![&env, target.clone()];
+        let fn_names = vec![&env, fn_name.clone()];
+        let datas: Vec<Vec<Val>> = vec![&env, data.clone()];
+        assert_eq!(
+            client.hash_batch_with_predecessors(
+                &targets,
+                &fn_names,
+                &datas,
+                &salt,
+                &vec![&env, first.clone()]
+            ),
+            client.hash_operation_batch(&targets, &fn_names, &datas, &salt, &Some(first.clone()))
+        );
     }
 
     #[test]
-    #[should_panic = "Error(Contract, #8)"]
-    fn executed_operation_should_panic() {
+    fn batch_is_ok() {
         let Context {
             env,
-            contract,
+            contract: contract_id,
             time_lock: client,
             proposer,
             executor,
@@ -1100,90 +1539,258 @@ mod cancel {
 
         let example_contract_id = env.register_contract(None, IncrementContract);
         let example_client = IncrementContractClient::new(&env, &example_contract_id);
-        example_client.initialize(&contract.clone());
+        example_client.initialize(&contract_id);
 
         let target = example_contract_id.clone();
         let fn_name = Symbol::new(&env, "increment");
-        let sum: u32 = 1000;
-        let data = (sum,).into_val(&env);
-        let delay: u64 = MIN_DELAY + 10;
+        let data: Vec<Val> = (1_u32,).into_val(&env);
+        let first_salt = BytesN::random(&env);
+        let first_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &first_salt, &None, &MIN_DELAY);
+
+        let targets = vec![&env, target.clone(), target.clone()];
+        let fn_names = vec![&env, fn_name.clone(), fn_name.clone()];
+        let datas: Vec<Vec<Val>> = vec![&env, data.clone(), data.clone()];
         let salt = BytesN::random(&env);
+        let predecessors = vec![&env, first_id.clone()];
+        let operation_id = client.schedule_batch_with_predecessors(
+            &proposer,
+            &targets,
+            &fn_names,
+            &datas,
+            &salt,
+            &predecessors,
+            &MIN_DELAY,
+        );
+        assert_eq!(
+            client.hash_batch_with_predecessors(&targets, &fn_names, &datas, &salt, &predecessors),
+            operation_id
+        );
 
-        let operation_id =
-            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
-
-        set_env_timestamp(&env, current_timestamp());
+        set_env_timestamp(&env, MIN_DELAY);
 
-        let predecessor: Option<BytesN<32>> = None;
-        client.execute(&executor, &target, &fn_name, &data, &salt, &predecessor);
+        assert_eq!(
+            client
+                .try_execute_batch_with_predecessors(
+                    &executor,
+                    &targets,
+                    &fn_names,
+                    &datas,
+                    &salt,
+                    &predecessors
+                )
+                .unwrap_err(),
+            Ok(Error::from_contract_error(
+                TimeLockError::PredecessorNotDone as u32
+            ))
+        );
 
-        client.cancel(&proposer, &operation_id);
+        client.execute(&executor, &target, &fn_name, &data, &first_salt, &None);
+        client.execute_batch_with_predecessors(
+            &executor,
+            &targets,
+            &fn_names,
+            &datas,
+            &salt,
+            &predecessors,
+        );
+        assert_eq!(example_client.get_count(), 3);
     }
 }
 
-mod update_min_delay {
+mod schedule_batch {
     use super::*;
-    use soroban_sdk::{vec, IntoVal, Symbol};
+    use soroban_sdk::{symbol_short, vec, Address, BytesN, IntoVal, String, Symbol, Val, Vec};
+
     #[test]
     fn is_ok() {
         let Context {
             env,
             contract: contract_id,
             time_lock: client,
-            proposer: _,
+            proposer,
             executor: _,
             owner: _,
         } = setup(true);
 
-        let delay = MIN_DELAY + 10;
-        client.update_min_delay(&delay);
-        assert_eq!(delay, client.get_min_delay());
+        let target = Address::generate(&env);
+        let target2 = Address::generate(&env);
+        let targets = vec![&env, target.clone(), target2.clone()];
+        let fn_names = vec![&env, symbol_short!("hello"), symbol_short!("world")];
+        let datas: Vec<Vec<Val>> = vec![
+            &env,
+            vec![&env, symbol_short!("lily").to_val()],
+            vec![&env],
+        ];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let predecessor: Option<BytesN<32>> = None;
+
+        let ledger_time = env.ledger().timestamp();
+        let operation_id = client.schedule_batch(
+            &proposer,
+            &targets,
+            &fn_names,
+            &datas,
+            &salt,
+            &predecessor,
+            &delay,
+        );
+
+        let expected_operation_id =
+            client.hash_operation_batch(&targets, &fn_names, &datas, &salt, &predecessor);
+        assert_eq!(operation_id, expected_operation_id);
+        assert_eq!(
+            client.get_schedule_lock_time(&operation_id),
+            ledger_time + delay
+        );
 
         let actual_events = env.events().all();
         let event_len = actual_events.len();
-        assert_eq!(event_len, 6);
+        assert_eq!(event_len, 7);
 
         assert_eq! {
-            actual_events.slice(event_len - 1..),
+            actual_events.slice(event_len - 2..),
             vec![
                 &env,
                 (
                     contract_id.clone(),
-                    (Symbol::new(&env, "MinDelayUpdated"),).into_val(&env),
-                    delay.into_val(&env)
+                    (Symbol::new(&env, "CallScheduled"),).into_val(&env),
+                    CallScheduledEvent {
+                        opt_id: operation_id.clone(),
+                        index: 0,
+                        target: target.clone(),
+                        fn_name: symbol_short!("hello"),
+                        data: datas.get(0).unwrap(),
+                        predecessors: vec![&env],
+                        delay,
+                        description: String::from_str(&env, ""),
+                    }.into_val(&env)
+                ),
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "CallScheduled"),).into_val(&env),
+                    CallScheduledEvent {
+                        opt_id: operation_id.clone(),
+                        index: 1,
+                        target: target2.clone(),
+                        fn_name: symbol_short!("world"),
+                        data: datas.get(1).unwrap(),
+                        predecessors: vec![&env],
+                        delay,
+                        description: String::from_str(&env, ""),
+                    }.into_val(&env)
                 )
             ]
         }
     }
 
     #[test]
-    fn not_owner_should_panic() {
-        let env = Env::default();
-        env.mock_all_auths();
-
+    fn with_description_is_ok() {
         let Context {
-            env: _,
-            contract: _,
+            env,
+            contract: contract_id,
             time_lock: client,
-            proposer: _,
+            proposer,
             executor: _,
             owner: _,
-        } = setup(false);
+        } = setup(true);
 
-        let delay = MIN_DELAY + 10;
+        let targets = vec![&env, Address::generate(&env), Address::generate(&env)];
+        let fn_names = vec![&env, symbol_short!("hello"), symbol_short!("world")];
+        let datas: Vec<Vec<Val>> = vec![&env, vec![&env], vec![&env]];
+        let salt = BytesN::random(&env);
+        let description = String::from_str(&env, "CHG-1234: rotate both signers");
+
+        let operation_id = client.schedule_batch_with_description(
+            &proposer,
+            &targets,
+            &fn_names,
+            &datas,
+            &salt,
+            &None,
+            &MIN_DELAY,
+            &description,
+        );
 
         assert_eq!(
-            client.try_update_min_delay(&delay),
-            Err(Ok(Error::from_contract_error(
-                OwnerError::OnlyOwner as u32
+            operation_id,
+            client.hash_operation_batch(&targets, &fn_names, &datas, &salt, &None)
+        );
+        assert_eq!(
+            client.get_operation(&operation_id).unwrap().description,
+            description
+        );
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "CallScheduled"),).into_val(&env),
+                    CallScheduledEvent {
+                        opt_id: operation_id.clone(),
+                        index: 1,
+                        target: targets.get(1).unwrap(),
+                        fn_name: symbol_short!("world"),
+                        data: vec![&env],
+                        predecessors: vec![&env],
+                        delay: MIN_DELAY,
+                        description: description.clone(),
+                    }.into_val(&env)
+                )
+            ]
+        }
+    }
+
+    #[test]
+    fn params_invalid_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let targets = vec![&env, Address::generate(&env), Address::generate(&env)];
+        let fn_names = vec![&env, symbol_short!("hello")];
+        let datas: Vec<Vec<Val>> = vec![&env, vec![&env], vec![&env]];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
+
+        assert_eq!(
+            client.try_schedule_batch(&proposer, &targets, &fn_names, &datas, &salt, &None, &delay),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidParams as u32
+            )))
+        );
+
+        assert_eq!(
+            client.try_schedule_batch(
+                &proposer,
+                &vec![&env],
+                &vec![&env],
+                &vec![&env],
+                &salt,
+                &None,
+                &delay
+            ),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidParams as u32
             )))
         );
     }
 
     #[test]
-    fn long_delay_should_panic() {
+    #[should_panic = "Error(Contract, #9)"]
+    fn not_proposer_should_panic() {
         let Context {
-            env: _,
+            env,
             contract: _,
             time_lock: client,
             proposer: _,
@@ -1191,550 +1798,3963 @@ mod update_min_delay {
             owner: _,
         } = setup(true);
 
-        let min_delay = 30 * 24 * 60 * 60 + 1;
+        let targets = vec![&env, Address::generate(&env)];
+        let fn_names = vec![&env, symbol_short!("hello")];
+        let datas: Vec<Vec<Val>> = vec![&env, vec![&env]];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
+
+        let caller = Address::generate(&env);
+        client.schedule_batch(&caller, &targets, &fn_names, &datas, &salt, &None, &delay);
+    }
+
+    #[test]
+    fn not_exist_native_function() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let targets = vec![&env, Address::generate(&env), contract_id.clone()];
+        let fn_names = vec![
+            &env,
+            symbol_short!("hello"),
+            Symbol::new(&env, "not_exist_function"),
+        ];
+        let datas: Vec<Vec<Val>> = vec![&env, vec![&env], vec![&env]];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
+
         assert_eq!(
-            client.try_update_min_delay(&min_delay),
+            client.try_schedule_batch(&proposer, &targets, &fn_names, &datas, &salt, &None, &delay),
             Err(Ok(Error::from_contract_error(
-                TimeLockError::DelayTooLong as u32
+                TimeLockError::InvalidFuncName as u32
             )))
         );
     }
 }
 
-mod grant_role {
+mod execute_batch {
     use super::*;
+    use soroban_sdk::{vec, Address, BytesN, FromVal, IntoVal, Symbol, Val, Vec};
 
-    mod grant_proposer_role {
-        use super::*;
-        use soroban_sdk::{Address, Env, IntoVal, Symbol};
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
 
-        #[test]
-        fn is_ok() {
-            let Context {
-                env,
-                contract: contract_id,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner,
-            } = setup(true);
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract_id);
 
-            let new_proposer = Address::generate(&env);
-            client.grant_role(&new_proposer, &RoleLabel::Proposer);
+        let account = Address::generate(&env);
+        let targets = vec![&env, example_contract_id.clone(), example_contract_id.clone()];
+        let fn_names = vec![
+            &env,
+            Symbol::new(&env, "increment"),
+            Symbol::new(&env, "increment_account_total"),
+        ];
+        let datas: Vec<Vec<Val>> = vec![
+            &env,
+            (1000_u32,).into_val(&env),
+            (account.clone(), 500_u32).into_val(&env),
+        ];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let predecessor: Option<BytesN<32>> = None;
 
-            if let Some(owner) = owner {
-                assert_eq!(
-                    env.auths(),
-                    std::vec![(
-                        owner.clone(),
-                        AuthorizedInvocation {
-                            function: AuthorizedFunction::Contract((
-                                contract_id.clone(),
-                                Symbol::new(&env, "grant_role"),
-                                (&new_proposer, RoleLabel::Proposer,).into_val(&env)
-                            )),
-                            sub_invocations: std::vec![]
-                        }
-                    )]
-                );
-            }
-            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer), true);
-        }
+        let operation_id = client.schedule_batch(
+            &proposer,
+            &targets,
+            &fn_names,
+            &datas,
+            &salt,
+            &predecessor,
+            &delay,
+        );
 
-        #[test]
-        fn duplicate_add() {
-            let Context {
-                env,
-                contract: _,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner: _,
-            } = setup(true);
+        set_env_timestamp(&env, current_timestamp());
 
-            let new_proposer = Address::generate(&env);
-            assert_eq!(client.grant_role(&new_proposer, &RoleLabel::Proposer), true);
-            assert_eq!(
-                client.grant_role(&new_proposer, &RoleLabel::Proposer),
-                false
-            );
+        let results =
+            client.execute_batch(&executor, &targets, &fn_names, &datas, &salt, &predecessor);
+        assert_eq!(u32::from_val(&env, &results.get(0).unwrap()), 1000);
+        assert_eq!(u32::from_val(&env, &results.get(1).unwrap()), 500);
 
-            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer), true);
-        }
+        assert_eq!(
+            env.auths(),
+            std::vec![(
+                executor.clone(),
+                AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        contract_id.clone(),
+                        Symbol::new(&env, "execute_batch"),
+                        (
+                            &executor,
+                            targets.clone(),
+                            fn_names.clone(),
+                            datas.clone(),
+                            salt.clone(),
+                            predecessor.clone(),
+                        )
+                            .into_val(&env)
+                    )),
+                    sub_invocations: std::vec![]
+                }
+            )]
+        );
 
-        #[test]
-        #[should_panic = "Error(Contract, #1001)"]
-        fn not_initialized_should_panic() {
-            let env = Env::default();
-            env.mock_all_auths();
+        assert_eq!(example_client.get_count(), 1000);
+        assert_eq!(example_client.get_account_total(&account), 500);
+        assert_eq!(client.get_schedule_lock_time(&operation_id), DONE_TIMESTAMP);
 
-            let contract_id = env.register_contract(None, TimeLockController);
-            let client = TimeLockControllerClient::new(&env, &contract_id);
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
 
-            let new_proposer = Address::generate(&env);
-            client.grant_role(&new_proposer, &RoleLabel::Proposer);
+        assert_eq! {
+            actual_events.slice(event_len - 2..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "CallExecuted"),).into_val(&env),
+                    CallExecutedEvent {
+                        opt_id: operation_id.clone(),
+                        index: 0,
+                        target: example_contract_id.clone(),
+                        fn_name: fn_names.get(0).unwrap(),
+                        data: datas.get(0).unwrap(),
+                        result: vec![&env, 1000_u32.into_val(&env)],
+                    }.into_val(&env)
+                ),
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "CallExecuted"),).into_val(&env),
+                    CallExecutedEvent {
+                        opt_id: operation_id.clone(),
+                        index: 1,
+                        target: example_contract_id.clone(),
+                        fn_name: fn_names.get(1).unwrap(),
+                        data: datas.get(1).unwrap(),
+                        result: vec![&env, 500_u32.into_val(&env)],
+                    }.into_val(&env)
+                )
+            ]
         }
     }
 
-    mod grant_executor_role {
-        use super::*;
-        use soroban_sdk::{Address, Env, IntoVal, Symbol};
-        #[test]
-        fn is_ok() {
-            let Context {
-                env,
-                contract: contract_id,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner,
-            } = setup(true);
+    #[test]
+    fn with_native_call() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(false);
 
-            let new_executor = Address::generate(&env);
-            client.grant_role(&new_executor, &RoleLabel::Executor);
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract_id);
 
-            if let Some(owner) = owner {
-                assert_eq!(
-                    env.auths(),
-                    std::vec![(
-                        owner.clone(),
-                        AuthorizedInvocation {
-                            function: AuthorizedFunction::Contract((
-                                contract_id.clone(),
-                                Symbol::new(&env, "grant_role"),
-                                (&new_executor, RoleLabel::Executor,).into_val(&env)
-                            )),
-                            sub_invocations: std::vec![]
-                        }
-                    )]
-                );
-            }
-            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor), true);
-        }
+        let new_delay = MIN_DELAY + 100;
+        let targets = vec![&env, contract_id.clone(), example_contract_id.clone()];
+        let fn_names = vec![
+            &env,
+            Symbol::new(&env, "update_min_delay"),
+            Symbol::new(&env, "increment"),
+        ];
+        let datas: Vec<Vec<Val>> = vec![
+            &env,
+            (new_delay,).into_val(&env),
+            (1000_u32,).into_val(&env),
+        ];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
 
-        #[test]
-        fn duplicate_add() {
-            let Context {
-                env,
-                contract: _,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner: _,
-            } = setup(true);
+        client.schedule_batch(&proposer, &targets, &fn_names, &datas, &salt, &None, &delay);
 
-            let new_executor = Address::generate(&env);
-            assert_eq!(client.grant_role(&new_executor, &RoleLabel::Executor), true);
-            assert_eq!(
-                client.grant_role(&new_executor, &RoleLabel::Executor),
-                false
-            );
+        set_env_timestamp(&env, current_timestamp());
 
-            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor), true);
-        }
-
-        #[test]
-        #[should_panic = "Error(Contract, #1001)"]
-        fn not_initialized_should_panic() {
-            let env = Env::default();
-            env.mock_all_auths();
-
-            let contract_id = env.register_contract(None, TimeLockController);
-            let client = TimeLockControllerClient::new(&env, &contract_id);
+        client.execute_batch(&executor, &targets, &fn_names, &datas, &salt, &None);
 
-            let new_executor = Address::generate(&env);
-            client.grant_role(&new_executor, &RoleLabel::Executor);
-        }
+        assert_eq!(client.get_min_delay(), new_delay);
+        assert_eq!(example_client.get_count(), 1000);
     }
 
-    mod grant_canceller_role {
-        use super::*;
-        use soroban_sdk::{Address, Env, IntoVal, Symbol};
+    #[test]
+    fn failed_call_reverts_batch() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
 
-        #[test]
-        fn is_ok() {
-            let Context {
-                env,
-                contract: contract_id,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner,
-            } = setup(true);
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract_id);
 
-            let new_canceller = Address::generate(&env);
-            client.grant_role(&new_canceller, &RoleLabel::Canceller);
+        let targets = vec![&env, example_contract_id.clone(), example_contract_id.clone()];
+        let fn_names = vec![
+            &env,
+            Symbol::new(&env, "increment"),
+            Symbol::new(&env, "increment_return_error"),
+        ];
+        let datas: Vec<Vec<Val>> = vec![
+            &env,
+            (1000_u32,).into_val(&env),
+            (1000_u32,).into_val(&env),
+        ];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
 
-            if let Some(owner) = owner {
-                assert_eq!(
-                    env.auths(),
-                    std::vec![(
-                        owner.clone(),
-                        AuthorizedInvocation {
-                            function: AuthorizedFunction::Contract((
-                                contract_id.clone(),
-                                Symbol::new(&env, "grant_role"),
-                                (&new_canceller, RoleLabel::Canceller,).into_val(&env)
-                            )),
-                            sub_invocations: std::vec![]
-                        }
-                    )]
-                );
-            }
+        let operation_id =
+            client.schedule_batch(&proposer, &targets, &fn_names, &datas, &salt, &None, &delay);
 
-            assert_eq!(client.has_role(&new_canceller, &RoleLabel::Canceller), true);
-        }
+        set_env_timestamp(&env, current_timestamp());
 
-        #[test]
-        fn duplicate_add() {
-            let Context {
-                env,
-                contract: _,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner: _,
-            } = setup(true);
+        assert_eq!(
+            client.try_execute_batch(&executor, &targets, &fn_names, &datas, &salt, &None).unwrap_err(),
+            Ok(Error::from_contract_error(
+                TimeLockError::ExecuteFailed as u32
+            ))
+        );
 
-            let new_canceller = Address::generate(&env);
-            assert_eq!(
-                client.grant_role(&new_canceller, &RoleLabel::Canceller),
-                true
-            );
-            assert_eq!(
-                client.grant_role(&new_canceller, &RoleLabel::Canceller),
-                false
-            );
+        assert_eq!(example_client.get_count(), 0);
+        assert_eq!(
+            client.get_schedule_lock_time(&operation_id),
+            MIN_DELAY + 10
+        );
+    }
 
-            assert_eq!(client.has_role(&new_canceller, &RoleLabel::Canceller), true);
-        }
+    #[test]
+    #[should_panic = "Error(Contract, #5)"]
+    fn operation_not_ready_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
 
-        #[test]
-        #[should_panic = "Error(Contract, #1001)"]
-        fn not_initialized_should_panic() {
-            let env = Env::default();
-            env.mock_all_auths();
+        let example_contract_id = env.register_contract(None, IncrementContract);
 
-            let Context {
-                env,
-                contract: _,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner: _,
-            } = setup(false);
+        let targets = vec![&env, example_contract_id.clone()];
+        let fn_names = vec![&env, Symbol::new(&env, "increment")];
+        let datas: Vec<Vec<Val>> = vec![&env, (1000_u32,).into_val(&env)];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
 
-            let new_canceller = Address::generate(&env);
-            client.grant_role(&new_canceller, &RoleLabel::Canceller);
-        }
+        client.schedule_batch(&proposer, &targets, &fn_names, &datas, &salt, &None, &delay);
+
+        client.execute_batch(&executor, &targets, &fn_names, &datas, &salt, &None);
     }
 }
 
-mod revoke_role {
+mod cancel {
     use super::*;
+    use soroban_sdk::{symbol_short, vec, Address, BytesN, IntoVal, Symbol};
 
-    mod revoke_proposer_role {
-        use super::*;
-        use soroban_sdk::{Address, Env, IntoVal, Symbol};
-
-        #[test]
-        fn is_ok() {
-            let Context {
-                env,
-                contract: contract_id,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner,
-            } = setup(true);
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
 
-            let new_proposer = Address::generate(&env);
-            client.grant_role(&new_proposer, &RoleLabel::Proposer);
-            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer), true);
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = vec![&env, symbol_short!("lily").to_val()];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let predecessor: Option<BytesN<32>> = None;
+        let operation_id = client.schedule(
+            &proposer,
+            &target,
+            &fn_name,
+            &data,
+            &salt,
+            &predecessor,
+            &delay,
+        );
 
-            client.revoke_role(&new_proposer, &RoleLabel::Proposer);
+        let ledger_time = env.ledger().timestamp();
+        let expect_lock_time = ledger_time + delay;
 
-            if let Some(owner) = owner {
-                assert_eq!(
-                    env.auths(),
-                    std::vec![(
-                        owner.clone(),
-                        AuthorizedInvocation {
-                            function: AuthorizedFunction::Contract((
-                                contract_id.clone(),
-                                Symbol::new(&env, "revoke_role"),
-                                (&new_proposer, RoleLabel::Proposer,).into_val(&env)
-                            )),
-                            sub_invocations: std::vec![]
-                        }
-                    )]
-                );
-            }
+        let actual_lock_time = client.get_schedule_lock_time(&operation_id);
+        assert_eq!(actual_lock_time, expect_lock_time);
 
-            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer), false);
-        }
+        set_env_timestamp(&env, current_timestamp());
 
-        #[test]
-        fn duplicate_revoke() {
-            let Context {
-                env,
-                contract: _,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner: _,
-            } = setup(true);
+        client.cancel(&proposer, &operation_id);
 
-            let new_proposer = Address::generate(&env);
-            client.grant_role(&new_proposer, &RoleLabel::Proposer);
-            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer), true);
+        assert_eq!(
+            env.auths(),
+            std::vec![(
+                proposer.clone(),
+                AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        contract_id.clone(),
+                        Symbol::new(&env, "cancel"),
+                        (&proposer, operation_id.clone(),).into_val(&env)
+                    )),
+                    sub_invocations: std::vec![]
+                }
+            )]
+        );
 
-            assert_eq!(
-                client.revoke_role(&new_proposer, &RoleLabel::Proposer),
-                true
-            );
+        assert_eq!(client.get_schedule_lock_time(&operation_id), 0);
+        assert_eq!(client.get_schedule_lock_time(&operation_id), 0);
 
-            assert_eq!(
-                client.revoke_role(&new_proposer, &RoleLabel::Proposer),
-                false
-            );
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq!(event_len, 7);
 
-            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer), false);
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "OperationCancelled"),).into_val(&env),
+                    CancelledEvent {
+                        opt_id: operation_id.clone(),
+                        canceller: proposer.clone(),
+                    }
+                    .into_val(&env)
+                )
+            ]
         }
+    }
 
-        #[test]
-        #[should_panic = "Error(Contract, #1001)"]
-        fn not_initialized_should_panic() {
-            let env = Env::default();
-            env.mock_all_auths();
-
-            let contract_id = env.register_contract(None, TimeLockController);
-            let client = TimeLockControllerClient::new(&env, &contract_id);
+    #[test]
+    fn operation_waiting_is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
 
-            let new_proposer = Address::generate(&env);
+        set_env_timestamp(&env, current_timestamp());
 
-            client.revoke_role(&new_proposer, &RoleLabel::Proposer);
-        }
-    }
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = vec![&env, symbol_short!("lily").to_val()];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let predecessor: Option<BytesN<32>> = None;
 
-    mod revoke_executor_role {
-        use super::*;
-        use soroban_sdk::{Address, Env, IntoVal, Symbol};
+        let operation_id = client.schedule(
+            &proposer,
+            &target,
+            &fn_name,
+            &data,
+            &salt,
+            &predecessor,
+            &delay,
+        );
 
-        #[test]
-        fn is_ok() {
-            let Context {
-                env,
-                contract: contract_id,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner,
-            } = setup(true);
+        let ledger_time = env.ledger().timestamp();
+        let expect_lock_time = ledger_time + delay;
 
-            let new_executor = Address::generate(&env);
-            client.grant_role(&new_executor, &RoleLabel::Executor);
-            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor), true);
+        let actual_lock_time = client.get_schedule_lock_time(&operation_id);
+        assert_eq!(actual_lock_time, expect_lock_time);
 
-            client.revoke_role(&new_executor, &RoleLabel::Executor);
+        client.cancel(&proposer, &operation_id);
 
-            if let Some(owner) = owner {
-                assert_eq!(
-                    env.auths(),
-                    std::vec![(
-                        owner.clone(),
-                        AuthorizedInvocation {
-                            function: AuthorizedFunction::Contract((
-                                contract_id.clone(),
-                                Symbol::new(&env, "revoke_role"),
-                                (&new_executor, RoleLabel::Executor,).into_val(&env)
-                            )),
-                            sub_invocations: std::vec![]
-                        }
-                    )]
-                );
-            }
+        assert_eq!(
+            env.auths(),
+            std::vec![(
+                proposer.clone(),
+                AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        contract_id.clone(),
+                        Symbol::new(&env, "cancel"),
+                        (&proposer, operation_id.clone(),).into_val(&env)
+                    )),
+                    sub_invocations: std::vec![]
+                }
+            )]
+        );
+
+        assert_eq!(client.get_schedule_lock_time(&operation_id), 0);
+        assert_eq!(client.get_schedule_lock_time(&operation_id), 0);
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq!(event_len, 7);
 
-            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor), false);
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "OperationCancelled"),).into_val(&env),
+                    CancelledEvent {
+                        opt_id: operation_id.clone(),
+                        canceller: proposer.clone(),
+                    }
+                    .into_val(&env)
+                )
+            ]
         }
+    }
 
-        #[test]
-        fn duplicate_revoke() {
-            let Context {
-                env,
-                contract: _,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner: _,
-            } = setup(true);
+    #[test]
+    #[should_panic = "Error(Contract, #9)"]
+    fn not_canceler_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
 
-            let new_executor = Address::generate(&env);
-            client.grant_role(&new_executor, &RoleLabel::Executor);
-            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor), true);
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = vec![&env, symbol_short!("lily").to_val()];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
 
-            assert_eq!(
-                client.revoke_role(&new_executor, &RoleLabel::Executor),
-                true
-            );
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
 
-            assert_eq!(
-                client.revoke_role(&new_executor, &RoleLabel::Executor),
-                false
-            );
+        set_env_timestamp(&env, current_timestamp());
 
-            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor), false);
-        }
+        let caller = Address::generate(&env);
+        client.cancel(&caller, &operation_id);
+    }
+    #[test]
+    #[should_panic = "Error(Contract, #8)"]
+    fn unset_operation_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
 
-        #[test]
-        #[should_panic = "Error(Contract, #1001)"]
-        fn not_initialized_should_panic() {
-            let env = Env::default();
-            env.mock_all_auths();
+        let operation_id = BytesN::random(&env);
 
-            let contract_id = env.register_contract(None, TimeLockController);
-            let client = TimeLockControllerClient::new(&env, &contract_id);
+        client.cancel(&proposer, &operation_id);
+    }
 
-            let new_executor = Address::generate(&env);
+    #[test]
+    #[should_panic = "Error(Contract, #8)"]
+    fn executed_operation_should_panic() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract.clone());
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let sum: u32 = 1000;
+        let data = (sum,).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        set_env_timestamp(&env, current_timestamp());
+
+        let predecessor: Option<BytesN<32>> = None;
+        client.execute(&executor, &target, &fn_name, &data, &salt, &predecessor);
+
+        client.cancel(&proposer, &operation_id);
+    }
+
+    #[test]
+    fn batch_is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = vec![&env, symbol_short!("lily").to_val()];
+        let first_id = client.schedule(
+            &proposer,
+            &target,
+            &fn_name,
+            &data,
+            &BytesN::random(&env),
+            &None,
+            &MIN_DELAY,
+        );
+        let second_id = client.schedule(
+            &proposer,
+            &target,
+            &fn_name,
+            &data,
+            &BytesN::random(&env),
+            &None,
+            &MIN_DELAY,
+        );
+
+        client.cancel_batch(&proposer, &vec![&env, first_id.clone(), second_id.clone()]);
 
-            client.revoke_role(&new_executor, &RoleLabel::Executor);
+        assert_eq!(client.get_schedule_lock_time(&first_id), 0);
+        assert_eq!(client.get_schedule_lock_time(&second_id), 0);
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq! {
+            actual_events.slice(event_len - 2..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "OperationCancelled"),).into_val(&env),
+                    CancelledEvent {
+                        opt_id: first_id.clone(),
+                        canceller: proposer.clone(),
+                    }
+                    .into_val(&env)
+                ),
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "OperationCancelled"),).into_val(&env),
+                    CancelledEvent {
+                        opt_id: second_id.clone(),
+                        canceller: proposer.clone(),
+                    }
+                    .into_val(&env)
+                )
+            ]
         }
     }
 
-    mod revoke_canceller_role {
-        use super::*;
-        use soroban_sdk::{Address, Env, IntoVal, Symbol};
+    #[test]
+    fn batch_with_invalid_id_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
 
-        #[test]
-        fn is_ok() {
-            let Context {
-                env,
-                contract: contract_id,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner,
-            } = setup(true);
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = vec![&env, symbol_short!("lily").to_val()];
+        let operation_id = client.schedule(
+            &proposer,
+            &target,
+            &fn_name,
+            &data,
+            &BytesN::random(&env),
+            &None,
+            &MIN_DELAY,
+        );
 
-            let new_canceller = Address::generate(&env);
-            client.grant_role(&new_canceller, &RoleLabel::Canceller);
-            assert_eq!(client.has_role(&new_canceller, &RoleLabel::Canceller), true);
+        assert_eq!(
+            client.try_cancel_batch(&proposer, &vec![&env, operation_id.clone(), BytesN::random(&env)]),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidStatus as u32
+            )))
+        );
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Waiting);
 
-            client.revoke_role(&new_canceller, &RoleLabel::Canceller);
+        assert_eq!(
+            client.try_cancel_batch(&proposer, &vec![&env]),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidParams as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn cancel_call_is_ok() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = vec![&env, symbol_short!("lily").to_val()];
+        let salt = BytesN::random(&env);
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY);
+
+        assert_eq!(
+            client.cancel_call(&proposer, &target, &fn_name, &data, &salt, &None),
+            operation_id
+        );
+        assert_eq!(client.get_schedule_lock_time(&operation_id), 0);
+    }
+
+    #[test]
+    fn reschedule_cancelled_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = vec![&env, symbol_short!("lily").to_val()];
+        let salt = BytesN::random(&env);
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY);
+
+        assert_eq!(
+            client.try_clear_cancellation(&proposer, &operation_id),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidStatus as u32
+            )))
+        );
+
+        client.cancel(&proposer, &operation_id);
+
+        assert_eq!(
+            client.try_schedule(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::OperationCancelled as u32
+            )))
+        );
+
+        let other_proposer = Address::generate(&env);
+        client.grant_role(&other_proposer, &RoleLabel::Proposer.to_symbol());
+        assert_eq!(
+            client.try_clear_cancellation(&other_proposer, &operation_id),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::NotPermitted as u32
+            )))
+        );
+
+        client.clear_cancellation(&proposer, &operation_id);
+        assert_eq!(client.get_operation(&operation_id), None);
+
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Waiting);
+    }
+}
+
+mod get_operation {
+    use super::*;
+    use soroban_sdk::{symbol_short, vec, xdr::ToXdr, Address, BytesN, IntoVal, String, Symbol, Val, Vec};
+
+    #[test]
+    fn scheduled_is_ok() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = vec![&env, symbol_short!("lily").to_val()];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let predecessor_id = BytesN::random(&env);
+        let predecessor = Some(predecessor_id.clone());
+
+        let ledger_time = env.ledger().timestamp();
+        let operation_id = client.schedule(
+            &proposer,
+            &target,
+            &fn_name,
+            &data,
+            &salt,
+            &predecessor,
+            &delay,
+        );
+
+        let datas: Vec<Vec<Val>> = vec![&env, data.clone()];
+        assert_eq!(
+            client.get_operation(&operation_id),
+            Some(Operation {
+                proposer: proposer.clone(),
+                targets: vec![&env, target.clone()],
+                fn_names: vec![&env, fn_name.clone()],
+                args_hash: env.crypto().sha256(&datas.to_xdr(&env)),
+                predecessors: vec![&env, predecessor_id],
+                scheduled_at: ledger_time,
+                ready_at: ledger_time + delay,
+                paused_offset: 0,
+                grace_period: 0,
+                executed_at: 0,
+                cancelled_at: 0,
+                executor: OperationExecutor::None,
+                status: OperationStatus::Pending,
+                last_failure: ExecutionFailure::None,
+                description: String::from_str(&env, ""),
+            })
+        );
+    }
+
+    #[test]
+    fn executed_is_ok() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        let now = current_timestamp();
+        set_env_timestamp(&env, now);
+
+        client.execute(&executor, &target, &fn_name, &data, &salt, &None);
+
+        let operation = client.get_operation(&operation_id).unwrap();
+        assert!(operation.predecessors.is_empty());
+        assert_eq!(operation.executed_at, now);
+        assert_eq!(operation.cancelled_at, 0);
+        assert_eq!(operation.executor, OperationExecutor::Account(executor.clone()));
+        assert_eq!(operation.executor.into_option(), Some(executor));
+        assert_eq!(operation.status, OperationStatus::Executed);
+    }
+
+    #[test]
+    fn cancelled_is_ok() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = vec![&env, symbol_short!("lily").to_val()];
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        let now = current_timestamp();
+        set_env_timestamp(&env, now);
+
+        client.cancel(&proposer, &operation_id);
+
+        let operation = client.get_operation(&operation_id).unwrap();
+        assert_eq!(operation.status, OperationStatus::Cancelled);
+        assert_eq!(operation.cancelled_at, now);
+        assert_eq!(operation.executed_at, 0);
+        assert_eq!(operation.executor, OperationExecutor::None);
+        assert_eq!(client.get_schedule_lock_time(&operation_id), 0);
+    }
+
+    #[test]
+    fn unset_is_none() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        assert_eq!(client.get_operation(&BytesN::random(&env)), None);
+    }
+}
+
+mod hash_operation {
+    use super::*;
+    use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+    use soroban_sdk::{symbol_short, vec, BytesN, IntoVal, Val, Vec};
+
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        let operation_id = client.hash_operation(&target, &fn_name, &data, &salt, &None);
+        assert_eq!(
+            operation_id,
+            hash_call_data(&env, &contract_id, &target, &fn_name, &data, &None, &salt)
+        );
+        assert_eq!(
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY),
+            operation_id
+        );
+
+        let predecessor = Some(BytesN::from_array(&env, &[0_u8; 32]));
+        assert_ne!(
+            client.hash_operation(&target, &fn_name, &data, &salt, &predecessor),
+            operation_id
+        );
+    }
+
+    #[test]
+    fn differs_between_instances() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let other_id = env.register_contract(None, TimeLockController);
+        let other_client = TimeLockControllerClient::new(&env, &other_id);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        assert_ne!(
+            client.hash_operation(&target, &fn_name, &data, &salt, &None),
+            other_client.hash_operation(&target, &fn_name, &data, &salt, &None)
+        );
+    }
+
+    #[test]
+    fn batch_is_ok() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let targets = vec![&env, Address::generate(&env), Address::generate(&env)];
+        let fn_names = vec![&env, symbol_short!("hello"), symbol_short!("world")];
+        let datas: Vec<Vec<Val>> = vec![
+            &env,
+            (1000_u32,).into_val(&env),
+            (2000_u32,).into_val(&env),
+        ];
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.hash_operation_batch(&targets, &fn_names, &datas, &salt, &None);
+        assert_eq!(
+            client.schedule_batch(&proposer, &targets, &fn_names, &datas, &salt, &None, &MIN_DELAY),
+            operation_id
+        );
+    }
+
+    #[test]
+    fn batch_invalid_params_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let targets = vec![&env, Address::generate(&env)];
+        let fn_names = vec![&env, symbol_short!("hello"), symbol_short!("world")];
+        let datas: Vec<Vec<Val>> = vec![&env, (1000_u32,).into_val(&env)];
+        let salt = BytesN::random(&env);
+
+        assert_eq!(
+            client.try_hash_operation_batch(&targets, &fn_names, &datas, &salt, &None),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidParams as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn with_auth_is_ok() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("hello");
+        let data: Vec<Val> = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+        let auth_entries = vec![
+            &env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: Address::generate(&env),
+                    fn_name: symbol_short!("world"),
+                    args: (7_u32,).into_val(&env),
+                },
+                sub_invocations: vec![&env],
+            }),
+        ];
+
+        assert_ne!(
+            client.hash_operation_with_auth(&target, &fn_name, &data, &salt, &None, &auth_entries),
+            client.hash_operation(&target, &fn_name, &data, &salt, &None)
+        );
+        assert_eq!(
+            client.hash_operation_with_auth(&target, &fn_name, &data, &salt, &None, &vec![&env]),
+            client.hash_operation(&target, &fn_name, &data, &salt, &None)
+        );
+    }
+}
+
+mod operation_state {
+    use super::*;
+    use soroban_sdk::{BytesN, IntoVal, Symbol};
+
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+
+        let operation_id = client.hash_operation(&target, &fn_name, &data, &salt, &None);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Unset);
+        assert!(!client.is_operation_pending(&operation_id));
+
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Waiting);
+        assert!(client.is_operation_pending(&operation_id));
+        assert!(!client.is_operation_ready(&operation_id));
+
+        set_env_timestamp(&env, delay);
+
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Ready);
+        assert!(client.is_operation_pending(&operation_id));
+        assert!(client.is_operation_ready(&operation_id));
+        assert!(!client.is_operation_done(&operation_id));
+
+        client.execute(&executor, &target, &fn_name, &data, &salt, &None);
+
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Executed);
+        assert!(!client.is_operation_pending(&operation_id));
+        assert!(!client.is_operation_ready(&operation_id));
+        assert!(client.is_operation_done(&operation_id));
+    }
+
+    #[test]
+    fn cancelled_is_unset() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+        client.cancel(&proposer, &operation_id);
+
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Unset);
+        assert!(!client.is_operation_pending(&operation_id));
+    }
+}
+
+mod pending_operations {
+    use super::*;
+    use soroban_sdk::{vec, BytesN, IntoVal, Symbol};
+    use time_lock::test::{DataKey, PendingOperation};
+    use time_lock_tests_common::{persistent_live_until, set_env_ledger};
+
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salts = [BytesN::random(&env), BytesN::random(&env), BytesN::random(&env)];
+        let delays = [MIN_DELAY, MIN_DELAY + 10, MIN_DELAY + 20];
+
+        assert_eq!(client.get_pending_operations(&0, &10), vec![&env]);
+
+        let mut operation_ids = std::vec::Vec::new();
+        for (salt, delay) in salts.iter().zip(delays.iter()) {
+            client.schedule(&proposer, &target, &fn_name, &data, salt, &None, delay);
+            operation_ids.push(client.hash_operation(&target, &fn_name, &data, salt, &None));
+        }
+
+        assert_eq!(
+            client.get_pending_operations(&0, &10),
+            vec![
+                &env,
+                PendingOperation {
+                    opt_id: operation_ids[0].clone(),
+                    ready_at: delays[0],
+                },
+                PendingOperation {
+                    opt_id: operation_ids[1].clone(),
+                    ready_at: delays[1],
+                },
+                PendingOperation {
+                    opt_id: operation_ids[2].clone(),
+                    ready_at: delays[2],
+                },
+            ]
+        );
+        assert_eq!(
+            client.get_pending_operations(&1, &1),
+            vec![
+                &env,
+                PendingOperation {
+                    opt_id: operation_ids[1].clone(),
+                    ready_at: delays[1],
+                },
+            ]
+        );
+        assert_eq!(client.get_pending_operations(&3, &10), vec![&env]);
+        assert_eq!(client.get_ready_operations(&0, &10), vec![&env]);
+
+        set_env_timestamp(&env, delays[1]);
+
+        assert_eq!(
+            client.get_ready_operations(&0, &10),
+            vec![
+                &env,
+                PendingOperation {
+                    opt_id: operation_ids[0].clone(),
+                    ready_at: delays[0],
+                },
+                PendingOperation {
+                    opt_id: operation_ids[1].clone(),
+                    ready_at: delays[1],
+                },
+            ]
+        );
+        assert_eq!(
+            client.get_ready_operations(&1, &10),
+            vec![
+                &env,
+                PendingOperation {
+                    opt_id: operation_ids[1].clone(),
+                    ready_at: delays[1],
+                },
+            ]
+        );
+
+        client.execute(&executor, &target, &fn_name, &data, &salts[0], &None);
+        client.cancel(&proposer, &operation_ids[2]);
+
+        assert_eq!(
+            client.get_pending_operations(&0, &10),
+            vec![
+                &env,
+                PendingOperation {
+                    opt_id: operation_ids[1].clone(),
+                    ready_at: delays[1],
+                },
+            ]
+        );
+        assert_eq!(
+            client.get_pending_operations(&1, &1),
+            vec![
+                &env,
+                PendingOperation {
+                    opt_id: operation_ids[1].clone(),
+                    ready_at: delays[1],
+                },
+            ]
+        );
+        assert_eq!(client.get_pending_operations(&2, &10), vec![&env]);
+        assert_eq!(client.get_pending_operations(&0, &0), vec![&env]);
+        assert_eq!(client.get_pending_index_len(), 3);
+    }
+
+    #[test]
+    fn expired_operations_are_pruned() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        client.update_grace_period(&100);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let expiring_salt = BytesN::random(&env);
+        let salt = BytesN::random(&env);
+
+        let expiring_id = client.schedule(&proposer, &target, &fn_name, &data, &expiring_salt, &None, &MIN_DELAY);
+        let operation_id = client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &(MIN_DELAY + 200));
+
+        assert_eq!(client.prune_expired_operations(&0, &10), 0);
+
+        set_env_timestamp(&env, MIN_DELAY + 101);
+
+        assert!(!client.is_operation_pending(&expiring_id));
+        assert_eq!(client.get_pending_operations(&0, &10).len(), 1);
+
+        assert_eq!(client.prune_expired_operations(&1, &10), 0);
+        assert_eq!(client.prune_expired_operations(&0, &10), 1);
+        assert_eq!(
+            client.get_pending_operations(&0, &10),
+            vec![
+                &env,
+                PendingOperation {
+                    opt_id: operation_id,
+                    ready_at: MIN_DELAY + 200,
+                },
+            ]
+        );
+
+        client.update_grace_period(&0);
+        assert_eq!(client.get_operation_state(&expiring_id), OperationState::Expired);
+        assert_eq!(
+            client.try_execute(&executor, &target, &fn_name, &data, &expiring_salt, &None).unwrap_err(),
+            Ok(Error::from_contract_error(
+                TimeLockError::OperationExpired as u32
+            ))
+        );
+
+        client.cancel(&proposer, &expiring_id);
+        assert_eq!(client.get_pending_operations(&0, &10).len(), 1);
+    }
+
+    #[test]
+    fn index_lives_as_long_as_its_operation() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        let operation_id = client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY);
+
+        let operation_key = DataKey::Scheduler(operation_id.clone());
+        let index_key = DataKey::PendingIndex(operation_id.clone());
+        let live_until = persistent_live_until(&env, operation_key.clone()).unwrap();
+        assert_eq!(persistent_live_until(&env, index_key.clone()), Some(live_until));
+        assert_eq!(persistent_live_until(&env, DataKey::PendingAt(0)), Some(live_until));
+
+        set_env_ledger(&env, 0, 50_000);
+        client.bump_operation(&operation_id);
+
+        let live_until = persistent_live_until(&env, operation_key).unwrap();
+        assert_eq!(persistent_live_until(&env, index_key), Some(live_until));
+        assert_eq!(persistent_live_until(&env, DataKey::PendingAt(0)), Some(live_until));
+    }
+
+    #[test]
+    fn failed_attempt_stays_pending() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment_return_error");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY);
+        set_env_timestamp(&env, MIN_DELAY);
+
+        assert!(!client.attempt_execute(&executor, &target, &fn_name, &data, &salt, &None));
+        assert_eq!(client.get_ready_operations(&0, &10).len(), 1);
+    }
+}
+
+mod ttl {
+    use super::*;
+    use soroban_sdk::{vec, Address, BytesN, IntoVal, Symbol};
+    use time_lock_tests_common::set_env_ledger;
+
+    const MAX_DELAY: u64 = 30 * 24 * 60 * 60;
+
+    #[test]
+    fn operation_survives_its_delay() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &MAX_DELAY);
+
+        env.as_contract(&example_contract_id, || {
+            env.storage().instance().extend_ttl(600_000, 600_000)
+        });
+
+        // a day of ledgers past the delay
+        set_env_ledger(&env, MAX_DELAY, (MAX_DELAY / 5) as u32 + 17_280);
+
+        client.execute(&executor, &target, &fn_name, &data, &salt, &None);
+
+        assert_eq!(example_client.get_count(), 1000);
+        assert_eq!(client.get_schedule_lock_time(&operation_id), DONE_TIMESTAMP);
+    }
+
+    #[test]
+    fn operation_survives_its_grace_period() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        client.update_grace_period(&MAX_DELAY);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &MAX_DELAY);
+
+        env.as_contract(&example_contract_id, || {
+            env.storage().instance().extend_ttl(1_100_000, 1_100_000)
+        });
+
+        let expires_at = 2 * MAX_DELAY;
+        set_env_ledger(&env, expires_at - 1, (expires_at / 5) as u32 - 1);
+
+        client.execute(&executor, &target, &fn_name, &data, &salt, &None);
+
+        assert_eq!(example_client.get_count(), 1000);
+    }
+
+    #[test]
+    fn bump_operation_is_ok() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY);
+
+        client.bump_operation(&operation_id);
+        client.bump_instance();
+
+        assert_eq!(env.auths(), std::vec![]);
+    }
+
+    #[test]
+    fn bump_unset_operation_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        assert_eq!(
+            client.try_bump_operation(&BytesN::random(&env)),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidStatus as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn update_ttl_margin_is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let margin: u32 = 1000;
+        client.update_ttl_margin(&margin);
+        assert_eq!(client.get_ttl_margin(), margin);
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "TtlMarginUpdated"),).into_val(&env),
+                    margin.into_val(&env)
+                )
+            ]
+        }
+    }
+
+    #[test]
+    fn update_ttl_margin_with_time_lock() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(false);
+
+        let target = contract_id.clone();
+        let fn_name = Symbol::new(&env, "update_ttl_margin");
+        let margin: u32 = 1000;
+        let data = (margin,).into_val(&env);
+        let salt = BytesN::random(&env);
+        let delay = MIN_DELAY + 10;
+
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        set_env_timestamp(&env, current_timestamp());
+
+        client.execute(&executor, &target, &fn_name, &data, &salt, &None);
+
+        assert_eq!(client.get_ttl_margin(), margin);
+        assert_eq!(
+            client.try_update_ttl_margin(&margin),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::OnlyOwner as u32
+            )))
+        );
+    }
+}
+
+mod min_delay_override {
+    use super::*;
+    use soroban_sdk::{symbol_short, vec, Address, BytesN, IntoVal, Symbol, Val, Vec};
+    use time_lock::test::DataKey;
+    use time_lock_tests_common::{persistent_live_until, set_env_ledger};
+
+    #[test]
+    fn get_min_delay_for_is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("upgrade");
+        let other_fn_name = symbol_short!("hello");
+
+        assert_eq!(client.get_min_delay_for(&target, &fn_name), MIN_DELAY);
+
+        client.update_target_min_delay(&target, &(MIN_DELAY + 100));
+        assert_eq!(client.get_min_delay_for(&target, &fn_name), MIN_DELAY + 100);
+        assert_eq!(
+            client.get_min_delay_for(&Address::generate(&env), &fn_name),
+            MIN_DELAY
+        );
+
+        client.update_fn_min_delay(&target, &fn_name, &(MIN_DELAY + 200));
+        assert_eq!(client.get_min_delay_for(&target, &fn_name), MIN_DELAY + 200);
+        assert_eq!(
+            client.get_min_delay_for(&target, &other_fn_name),
+            MIN_DELAY + 100
+        );
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "FnMinDelayUpdated"), target.clone(), fn_name.clone()).into_val(&env),
+                    (MIN_DELAY + 200).into_val(&env)
+                )
+            ]
+        }
+
+        client.update_target_min_delay(&target, &0);
+        client.update_fn_min_delay(&target, &fn_name, &0);
+        assert_eq!(client.get_min_delay_for(&target, &fn_name), MIN_DELAY);
+    }
+
+    #[test]
+    fn schedule_enforces_strictest_delay() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("upgrade");
+        let data = vec![&env, symbol_short!("lily").to_val()];
+        let salt = BytesN::random(&env);
+
+        client.update_fn_min_delay(&target, &fn_name, &(MIN_DELAY + 200));
+
+        assert_eq!(
+            client.try_schedule(&proposer, &target, &fn_name, &data, &salt, &None, &(MIN_DELAY + 10)),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InsufficientDelay as u32
+            )))
+        );
+
+        let targets = vec![&env, Address::generate(&env), target.clone()];
+        let fn_names = vec![&env, fn_name.clone(), fn_name.clone()];
+        let datas: Vec<Vec<Val>> = vec![&env, data.clone(), data.clone()];
+        assert_eq!(
+            client.try_schedule_batch(
+                &proposer,
+                &targets,
+                &fn_names,
+                &datas,
+                &salt,
+                &None,
+                &(MIN_DELAY + 10)
+            ),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InsufficientDelay as u32
+            )))
+        );
+
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &(MIN_DELAY + 200));
+        client.schedule_batch(
+            &proposer,
+            &targets,
+            &fn_names,
+            &datas,
+            &salt,
+            &None,
+            &(MIN_DELAY + 200),
+        );
+    }
+
+    #[test]
+    fn long_delay_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let delay = 30 * 24 * 60 * 60 + 1;
+        assert_eq!(
+            client.try_update_target_min_delay(&target, &delay),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::DelayTooLong as u32
+            )))
+        );
+        assert_eq!(
+            client.try_update_fn_min_delay(&target, &symbol_short!("upgrade"), &delay),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::DelayTooLong as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn override_is_kept_alive_when_read() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let target = Address::generate(&env);
+        let fn_name = symbol_short!("upgrade");
+        client.update_target_min_delay(&target, &(MIN_DELAY + 100));
+        client.update_fn_min_delay(&target, &fn_name, &(MIN_DELAY + 200));
+
+        let target_key = DataKey::TargetMinDelay(target.clone());
+        let fn_key = DataKey::FnMinDelay(target.clone(), fn_name.clone());
+        let live_until = persistent_live_until(&env, target_key.clone()).unwrap();
+        assert_eq!(persistent_live_until(&env, fn_key.clone()), Some(live_until));
+
+        set_env_ledger(&env, 0, 200_000);
+        assert_eq!(client.get_min_delay_for(&target, &fn_name), MIN_DELAY + 200);
+
+        assert_eq!(
+            persistent_live_until(&env, target_key),
+            Some(live_until + 200_000)
+        );
+        assert_eq!(
+            persistent_live_until(&env, fn_key),
+            Some(live_until + 200_000)
+        );
+    }
+
+    #[test]
+    fn update_with_time_lock() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(false);
+
+        let target = Address::generate(&env);
+        let upgrade = symbol_short!("upgrade");
+        let new_delay = MIN_DELAY + 100;
+        let fn_name = Symbol::new(&env, "update_fn_min_delay");
+        let data = (target.clone(), upgrade.clone(), new_delay).into_val(&env);
+        let fn_name_2 = Symbol::new(&env, "update_target_min_delay");
+        let data_2 = (target.clone(), new_delay + 100).into_val(&env);
+        let salt = BytesN::random(&env);
+        let delay = MIN_DELAY + 10;
+
+        client.schedule(&proposer, &contract_id, &fn_name, &data, &salt, &None, &delay);
+        client.schedule(&proposer, &contract_id, &fn_name_2, &data_2, &salt, &None, &delay);
+
+        set_env_timestamp(&env, delay);
+
+        client.execute(&executor, &contract_id, &fn_name, &data, &salt, &None);
+        assert_eq!(client.get_min_delay_for(&target, &upgrade), new_delay);
+
+        client.execute(&executor, &contract_id, &fn_name_2, &data_2, &salt, &None);
+        assert_eq!(client.get_min_delay_for(&target, &upgrade), new_delay + 100);
+
+        assert_eq!(
+            client.try_update_fn_min_delay(&target, &upgrade, &new_delay),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::OnlyOwner as u32
+            )))
+        );
+    }
+}
+
+mod grace_period {
+    use super::*;
+    use soroban_sdk::{vec, BytesN, IntoVal, Symbol};
+
+    const GRACE_PERIOD: u64 = 14 * 24 * 60 * 60;
+
+    #[test]
+    fn expired_should_panic() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        client.update_grace_period(&GRACE_PERIOD);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        set_env_timestamp(&env, delay + GRACE_PERIOD - 1);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Ready);
+
+        set_env_timestamp(&env, delay + GRACE_PERIOD);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Expired);
+        assert!(!client.is_operation_pending(&operation_id));
+        assert!(!client.is_operation_ready(&operation_id));
+
+        assert_eq!(
+            client.try_execute(&executor, &target, &fn_name, &data, &salt, &None).unwrap_err(),
+            Ok(Error::from_contract_error(
+                TimeLockError::OperationExpired as u32
+            ))
+        );
+
+        client.cancel(&proposer, &operation_id);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Unset);
+    }
+
+    #[test]
+    fn update_is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        assert_eq!(client.get_grace_period(), 0);
+
+        client.update_grace_period(&GRACE_PERIOD);
+        assert_eq!(client.get_grace_period(), GRACE_PERIOD);
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "GracePeriodUpdated"),).into_val(&env),
+                    GRACE_PERIOD.into_val(&env)
+                )
+            ]
+        }
+    }
+
+    #[test]
+    fn update_with_time_lock() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(false);
+
+        let target = contract_id.clone();
+        let fn_name = Symbol::new(&env, "update_grace_period");
+        let data = (GRACE_PERIOD,).into_val(&env);
+        let salt = BytesN::random(&env);
+        let delay = MIN_DELAY + 10;
+
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        set_env_timestamp(&env, delay);
+
+        client.execute(&executor, &target, &fn_name, &data, &salt, &None);
+
+        assert_eq!(client.get_grace_period(), GRACE_PERIOD);
+        assert_eq!(
+            client.try_update_grace_period(&GRACE_PERIOD),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::OnlyOwner as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn long_grace_period_should_panic() {
+        let Context {
+            env: _,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        assert_eq!(
+            client.try_update_grace_period(&(30 * 24 * 60 * 60 + 1)),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::GracePeriodTooLong as u32
+            )))
+        );
+        assert_eq!(
+            client.try_update_grace_period(&u64::MAX),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::GracePeriodTooLong as u32
+            )))
+        );
+        assert_eq!(client.get_grace_period(), 0);
+    }
+
+    #[test]
+    fn long_grace_period_with_time_lock_should_panic() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(false);
+
+        let target = contract_id.clone();
+        let fn_name = Symbol::new(&env, "update_grace_period");
+        let data = (u64::MAX,).into_val(&env);
+        let salt = BytesN::random(&env);
+        let delay = MIN_DELAY + 10;
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        set_env_timestamp(&env, delay);
+
+        assert_eq!(
+            client
+                .try_execute(&executor, &target, &fn_name, &data, &salt, &None)
+                .unwrap_err(),
+            Ok(Error::from_contract_error(
+                TimeLockError::GracePeriodTooLong as u32
+            ))
+        );
+        assert_eq!(client.get_grace_period(), 0);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Ready);
+    }
+}
+
+mod quorum {
+    use super::*;
+    use soroban_sdk::{vec, Address, BytesN, IntoVal, String, Symbol};
+
+    #[test]
+    fn approvals_reach_threshold() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let other_proposer = Address::generate(&env);
+        client.grant_role(&other_proposer, &RoleLabel::Proposer.to_symbol());
+        client.update_proposal_threshold(&2);
+        assert_eq!(client.get_proposal_threshold(), 2);
+
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+        let delay: u64 = MIN_DELAY + 10;
+
+        assert_eq!(
+            client.try_schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::ApprovalRequired as u32
+            )))
+        );
+
+        let operation_id =
+            client.propose(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+        assert_eq!(
+            operation_id,
+            client.hash_operation(&target, &fn_name, &data, &salt, &None)
+        );
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Unset);
+        assert_eq!(
+            client.get_proposal(&operation_id).unwrap().approvals,
+            vec![&env, proposer.clone()]
+        );
+
+        set_env_timestamp(&env, 100);
+        client.approve(&other_proposer, &operation_id);
+
+        assert_eq!(client.get_proposal(&operation_id), None);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Waiting);
+        let operation = client.get_operation(&operation_id).unwrap();
+        assert_eq!(operation.proposer, proposer);
+        assert_eq!(operation.scheduled_at, 100);
+        assert_eq!(operation.ready_at, 100 + delay);
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 2..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "OperationApproved"), operation_id.clone()).into_val(&env),
+                    (other_proposer.clone(), 2_u32).into_val(&env)
+                ),
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "CallScheduled"),).into_val(&env),
+                    CallScheduledEvent {
+                        opt_id: operation_id.clone(),
+                        index: 0,
+                        target: target.clone(),
+                        fn_name: fn_name.clone(),
+                        data: data.clone(),
+                        predecessors: vec![&env],
+                        delay,
+                        description: String::from_str(&env, ""),
+                    }
+                    .into_val(&env)
+                )
+            ]
+        }
+    }
+
+    #[test]
+    fn propose_with_predecessors_is_ok() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let other_proposer = Address::generate(&env);
+        client.grant_role(&other_proposer, &RoleLabel::Proposer.to_symbol());
+        client.update_proposal_threshold(&2);
+
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+        let predecessors = vec![&env, BytesN::random(&env), BytesN::random(&env)];
+
+        let operation_id = client.propose_with_predecessors(&proposer, &target, &fn_name, &data, &salt, &predecessors, &MIN_DELAY);
+        assert_eq!(
+            operation_id,
+            client.hash_operation_with_predecessors(&target, &fn_name, &data, &salt, &predecessors)
+        );
+        assert_eq!(client.get_proposal(&operation_id).unwrap().predecessors, predecessors);
+
+        client.approve(&other_proposer, &operation_id);
+        assert_eq!(client.get_operation(&operation_id).unwrap().predecessors, predecessors);
+
+        let targets = vec![&env, target.clone(), target.clone()];
+        let fn_names = vec![&env, fn_name.clone(), fn_name.clone()];
+        let datas = vec![&env, data.clone(), data.clone()];
+
+        let operation_id = client.propose_batch_with_predecessors(&proposer, &targets, &fn_names, &datas, &salt, &predecessors, &MIN_DELAY);
+        assert_eq!(
+            operation_id,
+            client.hash_batch_with_predecessors(&targets, &fn_names, &datas, &salt, &predecessors)
+        );
+
+        client.approve(&other_proposer, &operation_id);
+        assert_eq!(client.get_operation(&operation_id).unwrap().predecessors, predecessors);
+    }
+
+    #[test]
+    fn propose_with_description_is_ok() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let other_proposer = Address::generate(&env);
+        client.grant_role(&other_proposer, &RoleLabel::Proposer.to_symbol());
+        client.update_proposal_threshold(&2);
+
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+        let description = String::from_str(&env, "CHG-1234: mint the Q3 allocation");
+
+        let operation_id = client.propose_with_description(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY, &description);
+        assert_eq!(
+            operation_id,
+            client.hash_operation(&target, &fn_name, &data, &salt, &None)
+        );
+        assert_eq!(client.get_proposal(&operation_id).unwrap().description, description);
+
+        client.approve(&other_proposer, &operation_id);
+        assert_eq!(client.get_operation(&operation_id).unwrap().description, description);
+
+        let targets = vec![&env, target.clone(), target.clone()];
+        let fn_names = vec![&env, fn_name.clone(), fn_name.clone()];
+        let datas = vec![&env, data.clone(), data.clone()];
+
+        let operation_id = client.propose_batch_with_description(&proposer, &targets, &fn_names, &datas, &salt, &None, &MIN_DELAY, &description);
+        assert_eq!(
+            operation_id,
+            client.hash_operation_batch(&targets, &fn_names, &datas, &salt, &None)
+        );
+
+        client.approve(&other_proposer, &operation_id);
+        assert_eq!(client.get_operation(&operation_id).unwrap().description, description);
+    }
+
+    #[test]
+    fn propose_with_auth_is_ok() {
+        use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+        use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+        use soroban_sdk::{Val, Vec};
+    
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let other_proposer = Address::generate(&env);
+        client.grant_role(&other_proposer, &RoleLabel::Proposer.to_symbol());
+        client.update_proposal_threshold(&2);
+
+        let counter_id = env.register_contract(None, IncrementContract);
+        let counter_client = IncrementContractClient::new(&env, &counter_id);
+        counter_client.initialize(&contract_id);
+        let caller_id = env.register_contract(None, IncrementContract);
+
+        let target = caller_id.clone();
+        let fn_name = Symbol::new(&env, "increment_other");
+        let data: Vec<Val> = (counter_id.clone(), 7_u32).into_val(&env);
+        let salt = BytesN::random(&env);
+        let predecessor: Option<BytesN<32>> = None;
+        let auth_entries = vec![
+            &env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: counter_id.clone(),
+                    fn_name: Symbol::new(&env, "increment"),
+                    args: (7_u32,).into_val(&env),
+                },
+                sub_invocations: vec![&env],
+            }),
+        ];
+
+        let operation_id = client.propose_with_auth(&proposer, &target, &fn_name, &data, &salt, &predecessor, &MIN_DELAY, &auth_entries);
+        assert_eq!(
+            operation_id,
+            client.hash_operation_with_auth(&target, &fn_name, &data, &salt, &predecessor, &auth_entries)
+        );
+        assert!(client.get_proposal(&operation_id).unwrap().auth_entries == auth_entries);
+
+        client.approve(&other_proposer, &operation_id);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Waiting);
+
+        set_env_timestamp(&env, MIN_DELAY);
+
+        env.mock_auths(&[MockAuth {
+            address: &executor,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "execute_with_auth",
+                args: (
+                    &executor,
+                    &target,
+                    &fn_name,
+                    data.clone(),
+                    salt.clone(),
+                    predecessor.clone(),
+                    auth_entries.clone(),
+                )
+                    .into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        client.execute_with_auth(&executor, &target, &fn_name, &data, &salt, &predecessor, &auth_entries);
+
+        assert_eq!(counter_client.get_count(), 7);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Executed);
+    }
+
+    #[test]
+    fn approve_twice_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        client.update_proposal_threshold(&2);
+
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.propose(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY);
+
+        assert_eq!(
+            client.try_approve(&proposer, &operation_id),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::AlreadyApproved as u32
+            )))
+        );
+        assert_eq!(
+            client.try_propose(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::AlreadyExists as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn revoke_approval_is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let other_proposer = Address::generate(&env);
+        client.grant_role(&other_proposer, &RoleLabel::Proposer.to_symbol());
+        client.update_proposal_threshold(&3);
+
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.propose(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY);
+        client.approve(&other_proposer, &operation_id);
+
+        client.revoke_approval(&proposer, &operation_id);
+        assert_eq!(
+            client.get_proposal(&operation_id).unwrap().approvals,
+            vec![&env, other_proposer.clone()]
+        );
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "ApprovalRevoked"), operation_id.clone()).into_val(&env),
+                    (proposer.clone(), 1_u32).into_val(&env)
+                )
+            ]
+        }
+
+        assert_eq!(
+            client.try_revoke_approval(&proposer, &operation_id),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::NotApproved as u32
+            )))
+        );
+
+        client.revoke_approval(&other_proposer, &operation_id);
+        assert_eq!(client.get_proposal(&operation_id), None);
+        assert_eq!(
+            client.try_approve(&proposer, &operation_id),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidStatus as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn revoked_proposer_approvals_are_not_counted() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let second_proposer = Address::generate(&env);
+        let third_proposer = Address::generate(&env);
+        client.grant_role(&second_proposer, &RoleLabel::Proposer.to_symbol());
+        client.grant_role(&third_proposer, &RoleLabel::Proposer.to_symbol());
+        client.update_proposal_threshold(&2);
+
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.propose(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY);
+        client.revoke_role(&proposer, &RoleLabel::Proposer.to_symbol());
+
+        client.approve(&second_proposer, &operation_id);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Unset);
+        assert_eq!(
+            client.get_proposal(&operation_id).unwrap().approvals,
+            vec![&env, proposer.clone(), second_proposer.clone()]
+        );
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "OperationApproved"), operation_id.clone()).into_val(&env),
+                    (second_proposer.clone(), 1_u32).into_val(&env)
+                )
+            ]
+        }
+
+        client.approve(&third_proposer, &operation_id);
+        assert_eq!(client.get_proposal(&operation_id), None);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Waiting);
+    }
+
+    #[test]
+    fn not_proposer_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        client.update_proposal_threshold(&2);
+
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.propose(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY);
+
+        assert_eq!(
+            client.try_approve(&executor, &operation_id),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::NotPermitted as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn default_threshold_schedules_on_propose() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        assert_eq!(client.get_proposal_threshold(), 1);
+        assert_eq!(
+            client.try_update_proposal_threshold(&0),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidParams as u32
+            )))
+        );
+
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.propose(&proposer, &target, &fn_name, &data, &salt, &None, &MIN_DELAY);
+
+        assert_eq!(client.get_proposal(&operation_id), None);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Waiting);
+    }
+}
+
+mod open_execution {
+    use super::*;
+    use soroban_sdk::{vec, Address, BytesN, IntoVal, Symbol};
+
+    #[test]
+    fn anyone_can_execute() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        assert!(!client.is_open_execution());
+        client.update_open_execution(&true);
+        assert!(client.is_open_execution());
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "OpenExecutionUpdated"),).into_val(&env),
+                    true.into_val(&env)
+                )
+            ]
+        }
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract_id);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        let keeper = Address::generate(&env);
+        assert_eq!(
+            client.try_execute(&keeper, &target, &fn_name, &data, &salt, &None).unwrap_err(),
+            Ok(Error::from_contract_error(
+                TimeLockError::TimeNotReady as u32
+            ))
+        );
+
+        set_env_timestamp(&env, delay);
+        client.execute(&keeper, &target, &fn_name, &data, &salt, &None);
+
+        assert_eq!(example_client.get_count(), 1000);
+        assert_eq!(
+            client.get_operation(&operation_id).unwrap().executor,
+            OperationExecutor::Account(keeper)
+        );
+    }
+
+    #[test]
+    fn disabled_requires_executor_role() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        client.update_open_execution(&true);
+        client.update_open_execution(&false);
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+        set_env_timestamp(&env, delay);
+
+        assert_eq!(
+            client.try_execute(&Address::generate(&env), &target, &fn_name, &data, &salt, &None).unwrap_err(),
+            Ok(Error::from_contract_error(
+                TimeLockError::NotPermitted as u32
+            ))
+        );
+    }
+}
+
+mod pause {
+    use super::*;
+    use soroban_sdk::{vec, Address, BytesN, IntoVal, Symbol};
+
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let guardian = Address::generate(&env);
+        client.grant_role(&guardian, &RoleLabel::Guardian.to_symbol());
+
+        let target = Address::generate(&env);
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+        set_env_timestamp(&env, delay);
+
+        assert_eq!(
+            client.try_pause(&proposer),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::NotPermitted as u32
+            )))
+        );
+
+        client.pause(&guardian);
+        assert!(client.is_paused());
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "Paused"),).into_val(&env),
+                    guardian.into_val(&env)
+                )
+            ]
+        }
+
+        assert_eq!(
+            client.try_pause(&guardian),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::Paused as u32
+            )))
+        );
+        assert_eq!(
+            client.try_execute(&executor, &target, &fn_name, &data, &salt, &None).unwrap_err(),
+            Ok(Error::from_contract_error(
+                TimeLockError::Paused as u32
+            ))
+        );
+        assert_eq!(
+            client.try_schedule(&proposer, &target, &fn_name, &data, &BytesN::random(&env), &None, &delay),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::Paused as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn countdown_is_frozen() {
+        let Context {
+            env,
+            contract,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let guardian = Address::generate(&env);
+        client.grant_role(&guardian, &RoleLabel::Guardian.to_symbol());
+
+        let example_contract_id = env.register_contract(None, IncrementContract);
+        let example_client = IncrementContractClient::new(&env, &example_contract_id);
+        example_client.initialize(&contract);
+
+        let target = example_contract_id.clone();
+        let fn_name = Symbol::new(&env, "increment");
+        let data = (1000_u32,).into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        set_env_timestamp(&env, 100);
+        client.pause(&guardian);
+        set_env_timestamp(&env, 1100);
+        assert_eq!(client.get_schedule_lock_time(&operation_id), delay + 1000);
+        client.unpause();
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract.clone(),
+                    (Symbol::new(&env, "Unpaused"),).into_val(&env),
+                    1000_u64.into_val(&env)
+                )
+            ]
+        }
+
+        set_env_timestamp(&env, delay + 999);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Waiting);
+
+        set_env_timestamp(&env, delay + 1000);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Ready);
+        client.execute(&executor, &target, &fn_name, &data, &salt, &None);
+        assert_eq!(example_client.get_count(), 1000);
+    }
+
+    #[test]
+    fn unpause_through_time_lock() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        assert_eq!(
+            client.try_unpause(),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::NotPaused as u32
+            )))
+        );
+
+        let guardian = Address::generate(&env);
+        client.grant_role(&guardian, &RoleLabel::Guardian.to_symbol());
+        client.pause(&guardian);
+
+        let target = contract_id.clone();
+        let fn_name = Symbol::new(&env, "unpause");
+        let data = ().into_val(&env);
+        let delay: u64 = MIN_DELAY + 10;
+        let salt = BytesN::random(&env);
+
+        let operation_id =
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+        set_env_timestamp(&env, delay);
+        assert_eq!(client.get_operation_state(&operation_id), OperationState::Ready);
+        client.execute(&executor, &target, &fn_name, &data, &salt, &None);
+
+        assert!(!client.is_paused());
+    }
+}
+
+mod update_min_delay {
+    use super::*;
+    use soroban_sdk::{vec, IntoVal, Symbol};
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let delay = MIN_DELAY + 10;
+        client.update_min_delay(&delay);
+        assert_eq!(delay, client.get_min_delay());
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq!(event_len, 6);
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "MinDelayUpdated"),).into_val(&env),
+                    MinDelayChangedEvent {
+                        old: MIN_DELAY,
+                        new: delay,
+                    }
+                    .into_val(&env)
+                )
+            ]
+        }
+    }
+
+    #[test]
+    fn not_owner_should_panic() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let Context {
+            env: _,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(false);
+
+        let delay = MIN_DELAY + 10;
+
+        assert_eq!(
+            client.try_update_min_delay(&delay),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::OnlyOwner as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn long_delay_should_panic() {
+        let Context {
+            env: _,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let min_delay = 30 * 24 * 60 * 60 + 1;
+        assert_eq!(
+            client.try_update_min_delay(&min_delay),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::DelayTooLong as u32
+            )))
+        );
+    }
+}
+
+mod grant_role {
+    use super::*;
+
+    mod grant_proposer_role {
+        use super::*;
+        use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+        #[test]
+        fn is_ok() {
+            let Context {
+                env,
+                contract: contract_id,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner,
+            } = setup(true);
+
+            let new_proposer = Address::generate(&env);
+            client.grant_role(&new_proposer, &RoleLabel::Proposer.to_symbol());
+
+            if let Some(owner) = owner {
+                assert_eq!(
+                    env.auths(),
+                    std::vec![(
+                        owner.clone(),
+                        AuthorizedInvocation {
+                            function: AuthorizedFunction::Contract((
+                                contract_id.clone(),
+                                Symbol::new(&env, "grant_role"),
+                                (&new_proposer, RoleLabel::Proposer.to_symbol(),).into_val(&env)
+                            )),
+                            sub_invocations: std::vec![]
+                        }
+                    )]
+                );
+            }
+            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer.to_symbol()), true);
+        }
+
+        #[test]
+        fn duplicate_add() {
+            let Context {
+                env,
+                contract: _,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner: _,
+            } = setup(true);
+
+            let new_proposer = Address::generate(&env);
+            assert_eq!(client.grant_role(&new_proposer, &RoleLabel::Proposer.to_symbol()), true);
+            assert_eq!(
+                client.grant_role(&new_proposer, &RoleLabel::Proposer.to_symbol()),
+                false
+            );
+
+            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer.to_symbol()), true);
+        }
+
+        #[test]
+        #[should_panic = "Error(Contract, #1001)"]
+        fn not_initialized_should_panic() {
+            let env = Env::default();
+            env.mock_all_auths();
+
+            let contract_id = env.register_contract(None, TimeLockController);
+            let client = TimeLockControllerClient::new(&env, &contract_id);
+
+            let new_proposer = Address::generate(&env);
+            client.grant_role(&new_proposer, &RoleLabel::Proposer.to_symbol());
+        }
+    }
+
+    mod grant_executor_role {
+        use super::*;
+        use soroban_sdk::{Address, Env, IntoVal, Symbol};
+        #[test]
+        fn is_ok() {
+            let Context {
+                env,
+                contract: contract_id,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner,
+            } = setup(true);
+
+            let new_executor = Address::generate(&env);
+            client.grant_role(&new_executor, &RoleLabel::Executor.to_symbol());
+
+            if let Some(owner) = owner {
+                assert_eq!(
+                    env.auths(),
+                    std::vec![(
+                        owner.clone(),
+                        AuthorizedInvocation {
+                            function: AuthorizedFunction::Contract((
+                                contract_id.clone(),
+                                Symbol::new(&env, "grant_role"),
+                                (&new_executor, RoleLabel::Executor.to_symbol(),).into_val(&env)
+                            )),
+                            sub_invocations: std::vec![]
+                        }
+                    )]
+                );
+            }
+            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor.to_symbol()), true);
+        }
+
+        #[test]
+        fn duplicate_add() {
+            let Context {
+                env,
+                contract: _,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner: _,
+            } = setup(true);
+
+            let new_executor = Address::generate(&env);
+            assert_eq!(client.grant_role(&new_executor, &RoleLabel::Executor.to_symbol()), true);
+            assert_eq!(
+                client.grant_role(&new_executor, &RoleLabel::Executor.to_symbol()),
+                false
+            );
+
+            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor.to_symbol()), true);
+        }
+
+        #[test]
+        #[should_panic = "Error(Contract, #1001)"]
+        fn not_initialized_should_panic() {
+            let env = Env::default();
+            env.mock_all_auths();
+
+            let contract_id = env.register_contract(None, TimeLockController);
+            let client = TimeLockControllerClient::new(&env, &contract_id);
+
+            let new_executor = Address::generate(&env);
+            client.grant_role(&new_executor, &RoleLabel::Executor.to_symbol());
+        }
+    }
+
+    mod grant_canceller_role {
+        use super::*;
+        use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+        #[test]
+        fn is_ok() {
+            let Context {
+                env,
+                contract: contract_id,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner,
+            } = setup(true);
+
+            let new_canceller = Address::generate(&env);
+            client.grant_role(&new_canceller, &RoleLabel::Canceller.to_symbol());
+
+            if let Some(owner) = owner {
+                assert_eq!(
+                    env.auths(),
+                    std::vec![(
+                        owner.clone(),
+                        AuthorizedInvocation {
+                            function: AuthorizedFunction::Contract((
+                                contract_id.clone(),
+                                Symbol::new(&env, "grant_role"),
+                                (&new_canceller, RoleLabel::Canceller.to_symbol(),).into_val(&env)
+                            )),
+                            sub_invocations: std::vec![]
+                        }
+                    )]
+                );
+            }
+
+            assert_eq!(client.has_role(&new_canceller, &RoleLabel::Canceller.to_symbol()), true);
+        }
+
+        #[test]
+        fn duplicate_add() {
+            let Context {
+                env,
+                contract: _,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner: _,
+            } = setup(true);
+
+            let new_canceller = Address::generate(&env);
+            assert_eq!(
+                client.grant_role(&new_canceller, &RoleLabel::Canceller.to_symbol()),
+                true
+            );
+            assert_eq!(
+                client.grant_role(&new_canceller, &RoleLabel::Canceller.to_symbol()),
+                false
+            );
+
+            assert_eq!(client.has_role(&new_canceller, &RoleLabel::Canceller.to_symbol()), true);
+        }
+
+        #[test]
+        #[should_panic = "Error(Contract, #1001)"]
+        fn not_initialized_should_panic() {
+            let env = Env::default();
+            env.mock_all_auths();
+
+            let Context {
+                env,
+                contract: _,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner: _,
+            } = setup(false);
+
+            let new_canceller = Address::generate(&env);
+            client.grant_role(&new_canceller, &RoleLabel::Canceller.to_symbol());
+        }
+    }
+}
+
+mod revoke_role {
+    use super::*;
+
+    mod revoke_proposer_role {
+        use super::*;
+        use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+        #[test]
+        fn is_ok() {
+            let Context {
+                env,
+                contract: contract_id,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner,
+            } = setup(true);
+
+            let new_proposer = Address::generate(&env);
+            client.grant_role(&new_proposer, &RoleLabel::Proposer.to_symbol());
+            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer.to_symbol()), true);
+
+            client.revoke_role(&new_proposer, &RoleLabel::Proposer.to_symbol());
+
+            if let Some(owner) = owner {
+                assert_eq!(
+                    env.auths(),
+                    std::vec![(
+                        owner.clone(),
+                        AuthorizedInvocation {
+                            function: AuthorizedFunction::Contract((
+                                contract_id.clone(),
+                                Symbol::new(&env, "revoke_role"),
+                                (&new_proposer, RoleLabel::Proposer.to_symbol(),).into_val(&env)
+                            )),
+                            sub_invocations: std::vec![]
+                        }
+                    )]
+                );
+            }
+
+            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer.to_symbol()), false);
+        }
+
+        #[test]
+        fn duplicate_revoke() {
+            let Context {
+                env,
+                contract: _,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner: _,
+            } = setup(true);
+
+            let new_proposer = Address::generate(&env);
+            client.grant_role(&new_proposer, &RoleLabel::Proposer.to_symbol());
+            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer.to_symbol()), true);
+
+            assert_eq!(
+                client.revoke_role(&new_proposer, &RoleLabel::Proposer.to_symbol()),
+                true
+            );
+
+            assert_eq!(
+                client.revoke_role(&new_proposer, &RoleLabel::Proposer.to_symbol()),
+                false
+            );
+
+            assert_eq!(client.has_role(&new_proposer, &RoleLabel::Proposer.to_symbol()), false);
+        }
+
+        #[test]
+        #[should_panic = "Error(Contract, #1001)"]
+        fn not_initialized_should_panic() {
+            let env = Env::default();
+            env.mock_all_auths();
+
+            let contract_id = env.register_contract(None, TimeLockController);
+            let client = TimeLockControllerClient::new(&env, &contract_id);
+
+            let new_proposer = Address::generate(&env);
+
+            client.revoke_role(&new_proposer, &RoleLabel::Proposer.to_symbol());
+        }
+    }
+
+    mod revoke_executor_role {
+        use super::*;
+        use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+        #[test]
+        fn is_ok() {
+            let Context {
+                env,
+                contract: contract_id,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner,
+            } = setup(true);
+
+            let new_executor = Address::generate(&env);
+            client.grant_role(&new_executor, &RoleLabel::Executor.to_symbol());
+            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor.to_symbol()), true);
+
+            client.revoke_role(&new_executor, &RoleLabel::Executor.to_symbol());
+
+            if let Some(owner) = owner {
+                assert_eq!(
+                    env.auths(),
+                    std::vec![(
+                        owner.clone(),
+                        AuthorizedInvocation {
+                            function: AuthorizedFunction::Contract((
+                                contract_id.clone(),
+                                Symbol::new(&env, "revoke_role"),
+                                (&new_executor, RoleLabel::Executor.to_symbol(),).into_val(&env)
+                            )),
+                            sub_invocations: std::vec![]
+                        }
+                    )]
+                );
+            }
+
+            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor.to_symbol()), false);
+        }
+
+        #[test]
+        fn duplicate_revoke() {
+            let Context {
+                env,
+                contract: _,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner: _,
+            } = setup(true);
+
+            let new_executor = Address::generate(&env);
+            client.grant_role(&new_executor, &RoleLabel::Executor.to_symbol());
+            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor.to_symbol()), true);
+
+            assert_eq!(
+                client.revoke_role(&new_executor, &RoleLabel::Executor.to_symbol()),
+                true
+            );
+
+            assert_eq!(
+                client.revoke_role(&new_executor, &RoleLabel::Executor.to_symbol()),
+                false
+            );
+
+            assert_eq!(client.has_role(&new_executor, &RoleLabel::Executor.to_symbol()), false);
+        }
+
+        #[test]
+        #[should_panic = "Error(Contract, #1001)"]
+        fn not_initialized_should_panic() {
+            let env = Env::default();
+            env.mock_all_auths();
+
+            let contract_id = env.register_contract(None, TimeLockController);
+            let client = TimeLockControllerClient::new(&env, &contract_id);
+
+            let new_executor = Address::generate(&env);
+
+            client.revoke_role(&new_executor, &RoleLabel::Executor.to_symbol());
+        }
+    }
+
+    mod revoke_canceller_role {
+        use super::*;
+        use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+        #[test]
+        fn is_ok() {
+            let Context {
+                env,
+                contract: contract_id,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner,
+            } = setup(true);
+
+            let new_canceller = Address::generate(&env);
+            client.grant_role(&new_canceller, &RoleLabel::Canceller.to_symbol());
+            assert_eq!(client.has_role(&new_canceller, &RoleLabel::Canceller.to_symbol()), true);
+
+            client.revoke_role(&new_canceller, &RoleLabel::Canceller.to_symbol());
+
+            if let Some(owner) = owner {
+                assert_eq!(
+                    env.auths(),
+                    std::vec![(
+                        owner.clone(),
+                        AuthorizedInvocation {
+                            function: AuthorizedFunction::Contract((
+                                contract_id.clone(),
+                                Symbol::new(&env, "revoke_role"),
+                                (&new_canceller, RoleLabel::Canceller.to_symbol(),).into_val(&env)
+                            )),
+                            sub_invocations: std::vec![]
+                        }
+                    )]
+                );
+            }
+
+            assert_eq!(
+                client.has_role(&new_canceller, &RoleLabel::Canceller.to_symbol()),
+                false
+            );
+        }
+
+        #[test]
+        fn duplicate_revoke() {
+            let Context {
+                env,
+                contract: _,
+                time_lock: client,
+                proposer: _,
+                executor: _,
+                owner: _,
+            } = setup(true);
+
+            let new_canceller = Address::generate(&env);
+            client.grant_role(&new_canceller, &RoleLabel::Canceller.to_symbol());
+            assert_eq!(client.has_role(&new_canceller, &RoleLabel::Canceller.to_symbol()), true);
+
+            assert_eq!(
+                client.revoke_role(&new_canceller, &RoleLabel::Canceller.to_symbol()),
+                true
+            );
+
+            assert_eq!(
+                client.revoke_role(&new_canceller, &RoleLabel::Canceller.to_symbol()),
+                false
+            );
+
+            assert_eq!(
+                client.has_role(&new_canceller, &RoleLabel::Canceller.to_symbol()),
+                false
+            );
+        }
+
+        #[test]
+        #[should_panic = "Error(Contract, #1001)"]
+        fn not_initialized_should_panic() {
+            let env = Env::default();
+            env.mock_all_auths();
+
+            let contract_id = env.register_contract(None, TimeLockController);
+            let client = TimeLockControllerClient::new(&env, &contract_id);
+
+            let new_canceller = Address::generate(&env);
+
+            client.revoke_role(&new_canceller, &RoleLabel::Canceller.to_symbol());
+        }
+    }
+}
+
+mod role_members {
+    use super::*;
+    use soroban_sdk::{vec, Address};
+
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        assert_eq!(client.get_role_members(&RoleLabel::Proposer.to_symbol()), vec![&env, proposer.clone()]);
+        assert_eq!(client.get_role_members(&RoleLabel::Canceller.to_symbol()), vec![&env, proposer.clone()]);
+        assert_eq!(client.get_role_members(&RoleLabel::Executor.to_symbol()), vec![&env, executor.clone()]);
+        assert_eq!(client.get_role_member_count(&RoleLabel::Guardian.to_symbol()), 0);
+
+        let other_proposer = Address::generate(&env);
+        client.grant_role(&other_proposer, &RoleLabel::Proposer.to_symbol());
+        client.grant_role(&other_proposer, &RoleLabel::Proposer.to_symbol());
+
+        assert_eq!(client.get_role_member_count(&RoleLabel::Proposer.to_symbol()), 2);
+        assert_eq!(client.get_role_member(&RoleLabel::Proposer.to_symbol(), &0), proposer);
+        assert_eq!(client.get_role_member(&RoleLabel::Proposer.to_symbol(), &1), other_proposer);
+
+        client.revoke_role(&proposer, &RoleLabel::Proposer.to_symbol());
+        client.revoke_role(&proposer, &RoleLabel::Proposer.to_symbol());
+
+        assert_eq!(
+            client.get_role_members(&RoleLabel::Proposer.to_symbol()),
+            vec![&env, other_proposer.clone()]
+        );
+        assert_eq!(client.get_role_members(&RoleLabel::Canceller.to_symbol()), vec![&env, proposer.clone()]);
+
+        client.revoke_role(&executor, &RoleLabel::Executor.to_symbol());
+        assert_eq!(client.get_role_member_count(&RoleLabel::Executor.to_symbol()), 0);
+    }
+
+    #[test]
+    fn index_out_of_bounds_should_panic() {
+        let Context {
+            env: _,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        assert_eq!(
+            client.try_get_role_member(&RoleLabel::Proposer.to_symbol(), &1),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidParams as u32
+            )))
+        );
+    }
+}
+
+mod role_admin {
+    use super::*;
+    use soroban_sdk::{vec, Address, IntoVal, Symbol};
+    use time_lock::test::{RoleError, RoleKey};
+    use time_lock_tests_common::{persistent_live_until, set_env_ledger};
+
+    #[test]
+    fn set_role_admin_is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        assert_eq!(client.get_role_admin(&RoleLabel::Executor.to_symbol()), None);
+
+        client.set_role_admin(&RoleLabel::Executor.to_symbol(), &RoleLabel::Guardian.to_symbol());
+
+        assert_eq!(
+            client.get_role_admin(&RoleLabel::Executor.to_symbol()),
+            Some(RoleLabel::Guardian.to_symbol())
+        );
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq!(
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (
+                        Symbol::new(&env, "RoleAdminChanged"),
+                        RoleLabel::Executor.to_symbol()
+                    )
+                        .into_val(&env),
+                    (None::<Symbol>, RoleLabel::Guardian.to_symbol()).into_val(&env)
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn role_admin_is_kept_alive_when_read() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        client.set_role_admin(&RoleLabel::Executor.to_symbol(), &RoleLabel::Guardian.to_symbol());
+
+        let key = RoleKey::Admin(RoleLabel::Executor.to_symbol());
+        let live_until = persistent_live_until(&env, key.clone()).unwrap();
+
+        set_env_ledger(&env, 0, 200_000);
+        assert_eq!(
+            client.get_role_admin(&RoleLabel::Executor.to_symbol()),
+            Some(RoleLabel::Guardian.to_symbol())
+        );
+
+        assert_eq!(persistent_live_until(&env, key), Some(live_until + 200_000));
+    }
+
+    #[test]
+    fn admin_can_grant_and_revoke() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor,
+            owner: _,
+        } = setup(true);
+
+        let guardian = Address::generate(&env);
+        client.grant_role(&guardian, &RoleLabel::Guardian.to_symbol());
+        client.set_role_admin(&RoleLabel::Executor.to_symbol(), &RoleLabel::Guardian.to_symbol());
+
+        let new_executor = Address::generate(&env);
+        client.grant_role_as_admin(&guardian, &new_executor, &RoleLabel::Executor.to_symbol());
+        assert!(client.has_role(&new_executor, &RoleLabel::Executor.to_symbol()));
+
+        client.revoke_role_as_admin(&guardian, &executor, &RoleLabel::Executor.to_symbol());
+        assert!(!client.has_role(&executor, &RoleLabel::Executor.to_symbol()));
+        assert_eq!(
+            client.get_role_members(&RoleLabel::Executor.to_symbol()),
+            vec![&env, new_executor.clone()]
+        );
+    }
+
+    #[test]
+    fn without_admin_role_should_panic() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let account = Address::generate(&env);
+        assert_eq!(
+            client.try_grant_role_as_admin(&proposer, &account, &RoleLabel::Executor.to_symbol()),
+            Err(Ok(Error::from_contract_error(RoleError::MissingRole as u32)))
+        );
+
+        client.set_role_admin(&RoleLabel::Executor.to_symbol(), &RoleLabel::Guardian.to_symbol());
+        assert_eq!(
+            client.try_grant_role_as_admin(&proposer, &account, &RoleLabel::Executor.to_symbol()),
+            Err(Ok(Error::from_contract_error(RoleError::MissingRole as u32)))
+        );
+    }
+
+    #[test]
+    fn not_self_managed_should_panic() {
+        let Context {
+            env: _,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(false);
+
+        assert_eq!(
+            client.try_set_role_admin(&RoleLabel::Executor.to_symbol(), &RoleLabel::Guardian.to_symbol()),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::OnlyOwner as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn renounce_role_is_ok() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        client.renounce_role(&proposer, &RoleLabel::Proposer.to_symbol());
+        assert!(!client.has_role(&proposer, &RoleLabel::Proposer.to_symbol()));
+        assert!(client.has_role(&proposer, &RoleLabel::Canceller.to_symbol()));
+
+        let account = Address::generate(&env);
+        assert_eq!(
+            client.try_renounce_role(&account, &RoleLabel::Proposer.to_symbol()),
+            Err(Ok(Error::from_contract_error(RoleError::MissingRole as u32)))
+        );
+    }
+
+    #[test]
+    fn custom_role_is_ok() {
+        let Context {
+            env,
+            contract: _,
+            time_lock: client,
+            proposer,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let minter = Symbol::new(&env, "minter");
+        let guardian = Address::generate(&env);
+        client.grant_role(&guardian, &RoleLabel::Guardian.to_symbol());
+        client.set_role_admin(&minter, &RoleLabel::Guardian.to_symbol());
+        assert_eq!(client.get_role_admin(&minter), Some(RoleLabel::Guardian.to_symbol()));
+
+        let account = Address::generate(&env);
+        assert!(client.grant_role_as_admin(&guardian, &account, &minter));
+        assert!(client.has_role(&account, &minter));
+        assert!(!client.has_role(&account, &RoleLabel::Proposer.to_symbol()));
+        assert_eq!(client.get_role_members(&minter), vec![&env, account.clone()]);
+
+        assert_eq!(
+            client.try_grant_role_as_admin(&proposer, &account, &minter),
+            Err(Ok(Error::from_contract_error(RoleError::MissingRole as u32)))
+        );
+    }
+}
+
+mod update_owner {
+    use super::*;
+    use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
+
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner,
+        } = setup(true);
+
+        let new_owner = Address::generate(&env);
+        client.update_owner(&new_owner);
+
+        if let Some(owner) = owner.clone() {
+            assert_eq!(
+                env.auths(),
+                std::vec![(
+                    owner.clone(),
+                    AuthorizedInvocation {
+                        function: AuthorizedFunction::Contract((
+                            contract_id.clone(),
+                            Symbol::new(&env, "update_owner"),
+                            (&new_owner,).into_val(&env)
+                        )),
+                        sub_invocations: std::vec![]
+                    }
+                )]
+            );
+            assert!(client.is_owner(&owner));
+        }
+        assert_eq!(client.get_pending_owner(), Some(new_owner.clone()));
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "OwnershipTransferStarted"),).into_val(&env),
+                    OwnershipTransferStartedEvent {
+                        old: PreviousOwner::from(owner.clone()),
+                        new: new_owner.clone(),
+                    }
+                    .into_val(&env)
+                )
+            ]
+        }
+
+        client.accept_ownership();
+
+        assert_eq!(
+            env.auths(),
+            std::vec![(
+                new_owner.clone(),
+                AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        contract_id.clone(),
+                        Symbol::new(&env, "accept_ownership"),
+                        ().into_val(&env)
+                    )),
+                    sub_invocations: std::vec![]
+                }
+            )]
+        );
+        assert!(client.is_owner(&new_owner));
+        assert_eq!(client.get_pending_owner(), None);
+    }
+
+    #[test]
+    fn cancel_transfer_is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner,
+        } = setup(true);
+
+        let new_owner = Address::generate(&env);
+        client.update_owner(&new_owner);
+        client.cancel_ownership_transfer();
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "OwnershipTransferCancelled"),).into_val(&env),
+                    new_owner.into_val(&env)
+                )
+            ]
+        }
+
+        assert_eq!(client.get_pending_owner(), None);
+        assert!(client.is_owner(&owner.unwrap()));
+        assert_eq!(
+            client.try_accept_ownership(),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::NoPendingOwner as u32
+            )))
+        );
+        assert_eq!(
+            client.try_cancel_ownership_transfer(),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::NoPendingOwner as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn not_owner_should_panic() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let Context {
+            env: _,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(false);
+
+        let new_owner = Address::generate(&env);
+        assert_eq!(
+            client.try_update_owner(&new_owner),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::OnlyOwner as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn not_initialized_should_panic() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, TimeLockController);
+        let client = TimeLockControllerClient::new(&env, &contract_id);
+
+        let new_owner = Address::generate(&env);
+        assert_eq!(
+            client.try_update_owner(&new_owner),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::OnlyOwner as u32
+            )))
+        );
+    }
+}
+
+mod renounce_ownership {
+    use super::*;
+    use soroban_sdk::{vec, Address, BytesN, IntoVal, Symbol};
+
+    #[test]
+    fn is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner,
+        } = setup(true);
+
+        client.update_owner(&Address::generate(&env));
+        client.renounce_ownership();
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "OwnershipRenounced"),).into_val(&env),
+                    OwnershipRenouncedEvent {
+                        old: PreviousOwner::from(owner.clone()),
+                    }
+                    .into_val(&env)
+                )
+            ]
+        }
+
+        assert!(!client.is_owner(&owner.unwrap()));
+        assert_eq!(client.get_pending_owner(), None);
+        assert_eq!(
+            client.try_update_min_delay(&(MIN_DELAY + 10)),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::OnlyOwner as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn through_time_lock() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner,
+        } = setup(true);
+
+        let target = contract_id.clone();
+        let fn_name = Symbol::new(&env, "renounce_ownership");
+        let data = ().into_val(&env);
+        let salt = BytesN::random(&env);
+        let delay = MIN_DELAY + 10;
+
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+        set_env_timestamp(&env, delay);
+        client.execute(&executor, &target, &fn_name, &data, &salt, &None);
+
+        assert!(!client.is_owner(&owner.unwrap()));
+
+        let fn_name = Symbol::new(&env, "update_min_delay");
+        let data = (MIN_DELAY + 10,).into_val(&env);
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+        set_env_timestamp(&env, 2 * delay);
+        client.execute(&executor, &target, &fn_name, &data, &salt, &None);
+
+        assert_eq!(client.get_min_delay(), MIN_DELAY + 10);
+    }
+
+    #[test]
+    fn not_owner_should_panic() {
+        let Context {
+            env: _,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(false);
+
+        assert_eq!(
+            client.try_renounce_ownership(),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::OnlyOwner as u32
+            )))
+        );
+    }
+}
+
+mod typed_events {
+    use super::*;
+    use soroban_sdk::{vec, Address, IntoVal, Symbol};
+
+    #[test]
+    fn owner_grant_and_revoke_record_sender() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner,
+        } = setup(true);
+        let owner = owner.unwrap();
+
+        let account = Address::generate(&env);
+        client.grant_role(&account, &RoleLabel::Guardian.to_symbol());
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq!(
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "RoleGranted"), RoleLabel::Guardian.to_symbol()).into_val(&env),
+                    RoleGrantedEvent {
+                        role: RoleLabel::Guardian.to_symbol(),
+                        account: account.clone(),
+                        sender: owner.clone(),
+                    }
+                    .into_val(&env)
+                )
+            ]
+        );
+
+        client.revoke_role(&account, &RoleLabel::Guardian.to_symbol());
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq!(
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "RoleRevoked"), RoleLabel::Guardian.to_symbol()).into_val(&env),
+                    RoleRevokedEvent {
+                        role: RoleLabel::Guardian.to_symbol(),
+                        account: account.clone(),
+                        sender: owner.clone(),
+                    }
+                    .into_val(&env)
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn admin_and_renounce_record_sender() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
+
+        let guardian = Address::generate(&env);
+        client.grant_role(&guardian, &RoleLabel::Guardian.to_symbol());
+        client.set_role_admin(&RoleLabel::Executor.to_symbol(), &RoleLabel::Guardian.to_symbol());
+
+        let new_executor = Address::generate(&env);
+        client.grant_role_as_admin(&guardian, &new_executor, &RoleLabel::Executor.to_symbol());
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq!(
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "RoleGranted"), RoleLabel::Executor.to_symbol()).into_val(&env),
+                    RoleGrantedEvent {
+                        role: RoleLabel::Executor.to_symbol(),
+                        account: new_executor.clone(),
+                        sender: guardian.clone(),
+                    }
+                    .into_val(&env)
+                )
+            ]
+        );
+
+        client.renounce_role(&new_executor, &RoleLabel::Executor.to_symbol());
+
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq!(
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "RoleRevoked"), RoleLabel::Executor.to_symbol()).into_val(&env),
+                    RoleRevokedEvent {
+                        role: RoleLabel::Executor.to_symbol(),
+                        account: new_executor.clone(),
+                        sender: new_executor.clone(),
+                    }
+                    .into_val(&env)
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn accept_ownership_records_previous_owner() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner,
+        } = setup(true);
 
-            if let Some(owner) = owner {
-                assert_eq!(
-                    env.auths(),
-                    std::vec![(
-                        owner.clone(),
-                        AuthorizedInvocation {
-                            function: AuthorizedFunction::Contract((
-                                contract_id.clone(),
-                                Symbol::new(&env, "revoke_role"),
-                                (&new_canceller, RoleLabel::Canceller,).into_val(&env)
-                            )),
-                            sub_invocations: std::vec![]
-                        }
-                    )]
-                );
-            }
+        let new_owner = Address::generate(&env);
+        client.update_owner(&new_owner);
+        client.accept_ownership();
 
-            assert_eq!(
-                client.has_role(&new_canceller, &RoleLabel::Canceller),
-                false
-            );
-        }
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+        assert_eq!(
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "OwnerSet"),).into_val(&env),
+                    OwnerChangedEvent {
+                        old: PreviousOwner::Account(owner.unwrap()),
+                        new: new_owner.clone(),
+                    }
+                    .into_val(&env)
+                )
+            ]
+        );
+    }
+}
 
-        #[test]
-        fn duplicate_revoke() {
-            let Context {
-                env,
-                contract: _,
-                time_lock: client,
-                proposer: _,
-                executor: _,
-                owner: _,
-            } = setup(true);
+mod upgrade {
+    use super::*;
+    use soroban_sdk::token::Client as TokenClient;
+    use soroban_sdk::{vec, Address, BytesN, IntoVal, Symbol};
+    use time_lock::test::DataKey;
 
-            let new_canceller = Address::generate(&env);
-            client.grant_role(&new_canceller, &RoleLabel::Canceller);
-            assert_eq!(client.has_role(&new_canceller, &RoleLabel::Canceller), true);
+    const TOKEN_WASM: &[u8] =
+        include_bytes!("../../example/wasm/soroban_token_contract.optimized.wasm");
 
-            assert_eq!(
-                client.revoke_role(&new_canceller, &RoleLabel::Canceller),
-                true
-            );
+    #[test]
+    fn version_is_ok() {
+        let Context {
+            env: _,
+            contract: _,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
 
-            assert_eq!(
-                client.revoke_role(&new_canceller, &RoleLabel::Canceller),
-                false
-            );
+        assert_eq!(client.version(), 1);
+        assert_eq!(client.get_storage_version(), client.version());
+        assert_eq!(
+            client.try_migrate(),
+            Err(Ok(Error::from_contract_error(
+                TimeLockError::InvalidStatus as u32
+            )))
+        );
+    }
 
-            assert_eq!(
-                client.has_role(&new_canceller, &RoleLabel::Canceller),
-                false
-            );
-        }
+    #[test]
+    fn migrate_is_ok() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer: _,
+            executor: _,
+            owner: _,
+        } = setup(true);
 
-        #[test]
-        #[should_panic = "Error(Contract, #1001)"]
-        fn not_initialized_should_panic() {
-            let env = Env::default();
-            env.mock_all_auths();
+        env.as_contract(&contract_id, || {
+            env.storage().instance().remove(&DataKey::Version);
+        });
+        assert_eq!(client.get_storage_version(), 0);
 
-            let contract_id = env.register_contract(None, TimeLockController);
-            let client = TimeLockControllerClient::new(&env, &contract_id);
+        client.migrate();
 
-            let new_canceller = Address::generate(&env);
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
 
-            client.revoke_role(&new_canceller, &RoleLabel::Canceller);
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "Migrated"),).into_val(&env),
+                    (0_u32, 1_u32).into_val(&env)
+                )
+            ]
         }
-    }
-}
 
-mod update_owner {
-    use super::*;
-    use soroban_sdk::{Address, Env, IntoVal, Symbol};
+        assert_eq!(client.get_storage_version(), 1);
+    }
 
     #[test]
-    fn is_ok() {
+    fn upgrade_is_ok() {
         let Context {
             env,
             contract: contract_id,
             time_lock: client,
             proposer: _,
             executor: _,
-            owner,
+            owner: _,
         } = setup(true);
 
-        let new_owner = Address::generate(&env);
-        client.update_owner(&new_owner);
+        let new_wasm_hash = env.deployer().upload_contract_wasm(TOKEN_WASM);
+        client.upgrade(&new_wasm_hash);
 
-        if let Some(owner) = owner {
-            assert_eq!(
-                env.auths(),
-                std::vec![(
-                    owner.clone(),
-                    AuthorizedInvocation {
-                        function: AuthorizedFunction::Contract((
-                            contract_id.clone(),
-                            Symbol::new(&env, "update_owner"),
-                            (&new_owner,).into_val(&env)
-                        )),
-                        sub_invocations: std::vec![]
-                    }
-                )]
-            );
-        }   
+        let actual_events = env.events().all();
+        let event_len = actual_events.len();
+
+        assert_eq! {
+            actual_events.slice(event_len - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "Upgraded"),).into_val(&env),
+                    new_wasm_hash.into_val(&env)
+                )
+            ]
+        }
+
+        let token = TokenClient::new(&env, &contract_id);
+        assert_eq!(token.balance(&Address::generate(&env)), 0);
+        assert!(client.try_version().is_err());
     }
 
     #[test]
     fn not_owner_should_panic() {
-        let env = Env::default();
-        env.mock_all_auths();
-
         let Context {
-            env: _,
+            env,
             contract: _,
             time_lock: client,
             proposer: _,
@@ -1742,9 +5762,14 @@ mod update_owner {
             owner: _,
         } = setup(false);
 
-        let new_owner = Address::generate(&env);
         assert_eq!(
-            client.try_update_owner(&new_owner),
+            client.try_upgrade(&BytesN::random(&env)),
+            Err(Ok(Error::from_contract_error(
+                OwnerError::OnlyOwner as u32
+            )))
+        );
+        assert_eq!(
+            client.try_migrate(),
             Err(Ok(Error::from_contract_error(
                 OwnerError::OnlyOwner as u32
             )))
@@ -1752,19 +5777,30 @@ mod update_owner {
     }
 
     #[test]
-    fn not_initialized_should_panic() {
-        let env = Env::default();
-        env.mock_all_auths();
+    fn invoke_params_invalid() {
+        let Context {
+            env,
+            contract: contract_id,
+            time_lock: client,
+            proposer,
+            executor,
+            owner: _,
+        } = setup(false);
 
-        let contract_id = env.register_contract(None, TimeLockController);
-        let client = TimeLockControllerClient::new(&env, &contract_id);
+        let target = contract_id.clone();
+        let fn_name = Symbol::new(&env, "upgrade");
+        let data = (100_u64,).into_val(&env);
+        let salt = BytesN::random(&env);
+        let delay = MIN_DELAY + 10;
+
+        client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+        set_env_timestamp(&env, delay);
 
-        let new_owner = Address::generate(&env);
         assert_eq!(
-            client.try_update_owner(&new_owner),
-            Err(Ok(Error::from_contract_error(
-                OwnerError::OnlyOwner as u32
-            )))
+            client.try_execute(&executor, &target, &fn_name, &data, &salt, &None).unwrap_err(),
+            Ok(Error::from_contract_error(
+                TimeLockError::InvalidParams as u32
+            ))
         );
     }
 }
@@ -1788,6 +5824,8 @@ mod integrate_test_with_increment {
             &MIN_DELAY,
             &vec![&env, proposer.clone()],
             &vec![&env, executor.clone()],
+            &None,
+            &None,
             &Some(Address::generate(&env)),
         );
 
@@ -1829,6 +5867,8 @@ mod integrate_test_with_increment {
             &MIN_DELAY,
             &vec![&env, proposer.clone()],
             &vec![&env, executor.clone()],
+            &None,
+            &None,
             &Some(Address::generate(&env)),
         );
 
@@ -1866,6 +5906,8 @@ mod integrate_test_with_increment {
             &MIN_DELAY,
             &vec![&env, proposer.clone()],
             &vec![&env, executor.clone()],
+            &None,
+            &None,
             &Some(Address::generate(&env)),
         );
 
@@ -1902,6 +5944,8 @@ mod integrate_test_with_increment {
             &MIN_DELAY,
             &vec![&env, proposer.clone()],
             &vec![&env, executor.clone()],
+            &None,
+            &None,
             &Some(Address::generate(&env)),
         );
 
@@ -1970,6 +6014,8 @@ mod integrate_test_with_increment {
             &MIN_DELAY,
             &vec![&env, proposer.clone()],
             &vec![&env, executor.clone()],
+            &None,
+            &None,
             &Some(Address::generate(&env)),
         );
 
@@ -2154,17 +6200,17 @@ mod updata_self_with_time_lock {
             set_env_timestamp(&env, current_timestamp());
 
             assert_eq!(
-                client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor),
-                Err(Ok(Error::from_contract_error(
+                client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor).unwrap_err(),
+                Ok(Error::from_contract_error(
                     TimeLockError::InvalidParams as u32
-                )))
+                ))
             );
 
             assert_eq!(
-                client.try_execute(&executor, &target, &fn_name, &data_1, &salt, &predecessor),
-                Err(Ok(Error::from_contract_error(
+                client.try_execute(&executor, &target, &fn_name, &data_1, &salt, &predecessor).unwrap_err(),
+                Ok(Error::from_contract_error(
                     TimeLockError::InvalidParams as u32
-                )))
+                ))
             );
         }
 
@@ -2187,6 +6233,37 @@ mod updata_self_with_time_lock {
                 )))
             );
         }
+
+        #[test]
+        fn delay_too_long() {
+            let Context {
+                env,
+                contract: contract_id,
+                time_lock: client,
+                proposer,
+                executor,
+                owner: _,
+            } = setup(false);
+
+            let target = contract_id.clone();
+            let fn_name = Symbol::new(&env, "update_min_delay");
+            let new_delay: u64 = 30 * 24 * 60 * 60 + 1;
+            let data = (new_delay,).into_val(&env);
+            let salt = BytesN::random(&env);
+            let delay = MIN_DELAY + 10;
+
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+            set_env_timestamp(&env, current_timestamp());
+
+            assert_eq!(
+                client.try_execute(&executor, &target, &fn_name, &data, &salt, &None).unwrap_err(),
+                Ok(Error::from_contract_error(
+                    TimeLockError::DelayTooLong as u32
+                ))
+            );
+            assert_eq!(client.get_min_delay(), MIN_DELAY);
+        }
     }
 
     mod update_owner {
@@ -2265,11 +6342,18 @@ mod updata_self_with_time_lock {
                     }
                 )]
             );
+            if let Some(owner) = owner.clone() {
+                assert!(client.is_owner(&owner));
+            }
+            assert_eq!(client.get_pending_owner(), Some(new_owner.clone()));
+            assert_eq!(client.get_schedule_lock_time(&operation_id), DONE_TIMESTAMP);
+
+            client.accept_ownership();
+
             if let Some(owner) = owner {
                 assert_eq!(client.is_owner(&owner), false);
             }
             assert_eq!(client.is_owner(&new_owner), true);
-            assert_eq!(client.get_schedule_lock_time(&operation_id), DONE_TIMESTAMP);
         }
 
         #[test]
@@ -2314,17 +6398,17 @@ mod updata_self_with_time_lock {
             set_env_timestamp(&env, current_timestamp());
 
             assert_eq!(
-                client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor),
-                Err(Ok(Error::from_contract_error(
+                client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor).unwrap_err(),
+                Ok(Error::from_contract_error(
                     TimeLockError::InvalidParams as u32
-                )))
+                ))
             );
 
             assert_eq!(
-                client.try_execute(&executor, &target, &fn_name, &data_1, &salt, &predecessor),
-                Err(Ok(Error::from_contract_error(
+                client.try_execute(&executor, &target, &fn_name, &data_1, &salt, &predecessor).unwrap_err(),
+                Ok(Error::from_contract_error(
                     TimeLockError::InvalidParams as u32
-                )))
+                ))
             );
         }
 
@@ -2366,7 +6450,7 @@ mod updata_self_with_time_lock {
 
             let target = contract_id.clone();
             let fn_name = Symbol::new(&env, "grant_role");
-            let new_role = RoleLabel::Proposer;
+            let new_role = RoleLabel::Proposer.to_symbol();
             let new_address = Address::generate(&env);
             let data = (new_address.clone(), new_role.clone()).into_val(&env);
             let salt = BytesN::random(&env);
@@ -2431,6 +6515,33 @@ mod updata_self_with_time_lock {
             assert_eq!(client.get_schedule_lock_time(&operation_id), DONE_TIMESTAMP);
         }
 
+        #[test]
+        fn legacy_role_label_is_ok() {
+            let Context {
+                env,
+                contract: contract_id,
+                time_lock: client,
+                proposer,
+                executor,
+                owner: _,
+            } = setup(false);
+
+            let target = contract_id.clone();
+            let fn_name = Symbol::new(&env, "grant_role");
+            let new_address = Address::generate(&env);
+            let data = (new_address.clone(), RoleLabel::Executor).into_val(&env);
+            let salt = BytesN::random(&env);
+            let delay = MIN_DELAY + 10;
+
+            client.schedule(&proposer, &target, &fn_name, &data, &salt, &None, &delay);
+
+            set_env_timestamp(&env, current_timestamp());
+
+            client.execute(&executor, &target, &fn_name, &data, &salt, &None);
+
+            assert!(client.has_role(&new_address, &RoleLabel::Executor.to_symbol()));
+        }
+
         #[test]
         fn invoke_params_invalid() {
             let Context {
@@ -2444,7 +6555,7 @@ mod updata_self_with_time_lock {
 
             let target = contract_id.clone();
             let fn_name = Symbol::new(&env, "grant_role");
-            let valid_role = RoleLabel::Proposer;
+            let valid_role = RoleLabel::Proposer.to_symbol();
             let invalid_role = -100_i64;
             let invalid_address = 100_u64;
             let valid_address = Address::generate(&env);
@@ -2506,38 +6617,38 @@ mod updata_self_with_time_lock {
             set_env_timestamp(&env, current_timestamp());
 
             assert_eq!(
-                client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor),
-                Err(Ok(Error::from_contract_error(
+                client.try_execute(&executor, &target, &fn_name, &data, &salt, &predecessor).unwrap_err(),
+                Ok(Error::from_contract_error(
                     TimeLockError::InvalidParams as u32
-                )))
+                ))
             );
 
             assert_eq!(
-                client.try_execute(&executor, &target, &fn_name, &data_1, &salt, &predecessor),
-                Err(Ok(Error::from_contract_error(
+                client.try_execute(&executor, &target, &fn_name, &data_1, &salt, &predecessor).unwrap_err(),
+                Ok(Error::from_contract_error(
                     TimeLockError::InvalidParams as u32
-                )))
+                ))
             );
 
             assert_eq!(
-                client.try_execute(&executor, &target, &fn_name, &data_2, &salt, &predecessor),
-                Err(Ok(Error::from_contract_error(
+                client.try_execute(&executor, &target, &fn_name, &data_2, &salt, &predecessor).unwrap_err(),
+                Ok(Error::from_contract_error(
                     TimeLockError::InvalidParams as u32
-                )))
+                ))
             );
 
             assert_eq!(
-                client.try_execute(&executor, &target, &fn_name, &data_3, &salt, &predecessor),
-                Err(Ok(Error::from_contract_error(
+                client.try_execute(&executor, &target, &fn_name, &data_3, &salt, &predecessor).unwrap_err(),
+                Ok(Error::from_contract_error(
                     TimeLockError::InvalidParams as u32
-                )))
+                ))
             );
 
             assert_eq!(
-                client.try_execute(&executor, &target, &fn_name, &data_4, &salt, &predecessor),
-                Err(Ok(Error::from_contract_error(
+                client.try_execute(&executor, &target, &fn_name, &data_4, &salt, &predecessor).unwrap_err(),
+                Ok(Error::from_contract_error(
                     TimeLockError::InvalidParams as u32
-                )))
+                ))
             );
         }
 
@@ -2552,7 +6663,7 @@ mod updata_self_with_time_lock {
                 owner: _,
             } = setup(false);
 
-            let new_role = RoleLabel::Proposer;
+            let new_role = RoleLabel::Proposer.to_symbol();
             let new_address = Address::generate(&env);
             assert_eq!(
                 client.try_grant_role(&new_address, &new_role),
@@ -2580,7 +6691,7 @@ mod updata_self_with_time_lock {
 
             let target = contract_id.clone();
             let fn_name = Symbol::new(&env, "revoke_role");
-            let role = RoleLabel::Proposer;
+            let role = RoleLabel::Proposer.to_symbol();
             let data = (proposer.clone(), role.clone()).into_val(&env);
             let salt = BytesN::random(&env);
             let delay = MIN_DELAY + 10;
@@ -2657,7 +6768,7 @@ mod updata_self_with_time_lock {
                 owner: _,
             } = setup(false);
 
-            let role = RoleLabel::Proposer;
+            let role = RoleLabel::Proposer.to_symbol();
             assert_eq!(
                 client.try_revoke_role(&proposer, &role),
                 Err(Ok(Error::from_contract_error(
@@ -8,14 +8,21 @@
  * this contract can also self administered, meaning administration tasks have to
  * go through the timelock process.
  */
-use crate::role_base;
-use crate::role_base::RoleLabel;
+// Entry points, and the client generated from them, take the call parameters
+// as separate arguments: they are the contract's public interface.
+#![allow(clippy::too_many_arguments)]
+
 use crate::time_lock;
-use crate::time_lock::{DataKey, TimeLockError};
+use crate::time_lock::{
+    Call, DataKey, Operation, OperationState, PendingOperation, Proposal, RoleLabel,
+    TimeLockError, ROLE_TTL,
+};
 use owner::owner;
+use role_base::role_base;
 
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, Address, BytesN, Env, Symbol, Val, Vec,
+    auth::InvokerContractAuthEntry, contract, contractimpl, panic_with_error, Address, BytesN,
+    Env, String, Symbol, Val, Vec,
 };
 
 #[contract]
@@ -27,19 +34,33 @@ impl TimeLockController {
      *  Initializes the contract with the following parameters:
      *
      * - `min_delay`: initial minimum delay in seconds for operations
-     * - `proposers`: accounts to be granted proposer and canceller roles
+     * - `proposers`: accounts to be granted proposer role
      * - `executors`: accounts to be granted executor role
+     * - `cancellers`: accounts to be granted canceller role, if None the proposers are granted it
+     * - `guardians`: accounts to be granted guardian role, if None no account is granted it
      * - `owner`: account to be granted owner role, the owner can run management tasks directly,
      *  if None the contract will only be self managed.
+     *
+     * Each list must not contain the same account twice.
      */
     pub fn initialize(
         e: Env,
         min_delay: u64,
         proposers: Vec<Address>,
         executors: Vec<Address>,
+        cancellers: Option<Vec<Address>>,
+        guardians: Option<Vec<Address>>,
         owner: Option<Address>,
     ) {
-        time_lock::initialize(&e, min_delay, &proposers, &executors, &owner)
+        time_lock::initialize(
+            &e,
+            min_delay,
+            &proposers,
+            &executors,
+            &cancellers,
+            &guardians,
+            &owner,
+        )
     }
 
     /*
@@ -51,6 +72,8 @@ impl TimeLockController {
      *
      * - the caller must have the 'proposer' role.
      * - if the target is the timelock itself, the caller must have the 'owner' role.
+     * - the proposal threshold must be 1, otherwise operations go through {propose}.
+     * - the timelock must not be paused.
      */
     pub fn schedule(
         e: Env,
@@ -68,18 +91,62 @@ impl TimeLockController {
 
         Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
 
-        time_lock::schedule(&e, &target, &fn_name, &data, &salt, &predecessor, delay)
+        let call = Call { target, fn_name, data };
+        time_lock::schedule(&e, &proposer, &call, &salt, &predecessor, delay)
+    }
+
+    /*
+     * Schedule an operation containing a single transaction, with a description
+     * for reviewers, for example a link to a change-management ticket. The
+     * description is stored with the operation and included in the event, but
+     * it is not part of the operation id.
+     *
+     * Emits a {CallScheduled} event.
+     *
+     * Requirements:
+     *
+     * - same as {schedule}.
+     */
+    pub fn schedule_with_description(
+        e: Env,
+        proposer: Address,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+        delay: u64,
+        description: String,
+    ) -> BytesN<32> {
+        if target == e.current_contract_address() {
+            Self::_check_fn_name(&e, &fn_name);
+        }
+
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let call = Call { target, fn_name, data };
+        time_lock::schedule_with_description(
+            &e,
+            &proposer,
+            &call,
+            &salt,
+            &predecessor,
+            delay,
+            &description,
+        )
     }
 
     /*
      * Execute an (ready) operation containing a single transaction.
+     * Returns the value returned by the call.
      *
      * Emits a {CallExecuted} event.
      *
      * Requirements:
      *
-     * - the caller must have the 'executor' role.
+     * - the caller must have the 'executor' role, unless open execution is enabled.
      * - if the target is the timelock itself, the caller must have the 'owner' role.
+     * - the timelock must not be paused.
      */
     pub fn execute(
         e: Env,
@@ -89,139 +156,1350 @@ impl TimeLockController {
         data: Vec<Val>,
         salt: BytesN<32>,
         predecessor: Option<BytesN<32>>,
-    ) {
-        let mut is_native = false;
+    ) -> Val {
+        Self::_check_executor(&e, &executor);
+
+        let call = Call { target, fn_name, data };
+        time_lock::execute(&e, &executor, &call, &salt, &predecessor)
+    }
+
+    /*
+     * Schedule an operation containing a single transaction, together with the
+     * sub-invocations the timelock authorizes on its own behalf right before
+     * executing it, for targets that call other contracts requiring the
+     * timelock's authorization. The auth entries are part of the operation id.
+     *
+     * Emits a {CallScheduled} event.
+     *
+     * Requirements:
+     *
+     * - same as {schedule}.
+     */
+    pub fn schedule_with_auth(
+        e: Env,
+        proposer: Address,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+        delay: u64,
+        auth_entries: Vec<InvokerContractAuthEntry>,
+    ) -> BytesN<32> {
         if target == e.current_contract_address() {
-            is_native = true;
-        } 
+            Self::_check_fn_name(&e, &fn_name);
+        }
+
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
 
-        Self::_check_role(&e, &executor, &RoleLabel::Executor);
-        
-        time_lock::execute(&e, &target, &fn_name, &data, &salt, &predecessor, is_native);
+        let call = Call { target, fn_name, data };
+        time_lock::schedule_with_auth(
+            &e,
+            &proposer,
+            &call,
+            &salt,
+            &predecessor,
+            delay,
+            &auth_entries,
+        )
     }
 
     /*
-     * Cancel an operation.
+     * Execute an (ready) operation scheduled with {schedule_with_auth},
+     * authorizing `auth_entries` on behalf of the timelock before the call.
+     *
+     * Emits a {CallExecuted} event.
      *
      * Requirements:
      *
-     * - the caller must have the 'canceller' role.
+     * - same as {execute}.
      */
-    pub fn cancel(e: Env, canceller: Address, operation_id: BytesN<32>) {
-        Self::_check_role(&e, &canceller, &RoleLabel::Canceller);
+    pub fn execute_with_auth(
+        e: Env,
+        executor: Address,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+        auth_entries: Vec<InvokerContractAuthEntry>,
+    ) -> Val {
+        Self::_check_executor(&e, &executor);
 
-        time_lock::cancel(&e, &operation_id)
+        let call = Call { target, fn_name, data };
+        time_lock::execute_with_auth(&e, &executor, &call, &salt, &predecessor, &auth_entries)
     }
 
     /*
-     * Changes the minimum timelock duration for future operations.
+     * Execute an (ready) operation containing a single transaction without
+     * reverting when the target call fails. The target's contract error code,
+     * or the host error, is recorded on the operation, which stays pending
+     * and can be attempted again.
      *
-     * Emits a {MinDelayUpdated} event.
+     * Returns `true` if the call succeeded.
+     *
+     * Emits a {CallExecuted} event on success, a {CallFailed} event otherwise.
      *
      * Requirements:
      *
-     * - if the timelock is self-managed, caller can direct the timelock to update the min delay. In this case,
-     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
-     * first schedule an operation where the timelock is the target. then execute the operation.
+     * - the caller must have the 'executor' role, unless open execution is enabled.
+     * - the timelock must not be paused.
      */
-    pub fn update_min_delay(e: Env, delay: u64) {
-        if delay > time_lock::MAX_MIN_DELAY {
-            panic_with_error!(e, TimeLockError::DelayTooLong);
+    pub fn attempt_execute(
+        e: Env,
+        executor: Address,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+    ) -> bool {
+        Self::_check_executor(&e, &executor);
+
+        let call = Call { target, fn_name, data };
+        time_lock::attempt_execute(&e, &executor, &call, &salt, &predecessor)
+    }
+
+    /*
+     * Schedule an operation containing a batch of transactions.
+     *
+     * The calls are hashed together into a single operation id and will be
+     * executed atomically, in order, by {execute_batch}.
+     *
+     * Emits one {CallScheduled} event per call.
+     *
+     * Requirements:
+     *
+     * - the caller must have the 'proposer' role.
+     * - `targets`, `fn_names` and `datas` must be non-empty and of the same length.
+     * - if a target is the timelock itself, the function must be one of the native functions.
+     * - the proposal threshold must be 1, otherwise operations go through {propose_batch}.
+     * - the timelock must not be paused.
+     */
+    pub fn schedule_batch(
+        e: Env,
+        proposer: Address,
+        targets: Vec<Address>,
+        fn_names: Vec<Symbol>,
+        datas: Vec<Vec<Val>>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+        delay: u64,
+    ) -> BytesN<32> {
+        for (target, fn_name) in targets.iter().zip(fn_names.iter()) {
+            if target == e.current_contract_address() {
+                Self::_check_fn_name(&e, &fn_name);
+            }
         }
 
-        owner::only_owner(&e);
-        time_lock::update_min_delay(&e, delay);
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let calls = time_lock::batch_calls(&e, &targets, &fn_names, &datas);
+        time_lock::schedule_batch(&e, &proposer, &calls, &salt, &predecessor, delay)
     }
 
     /*
-     * Grants a role to an account.
+     * Schedule an operation containing a batch of transactions, with a
+     * description for reviewers, see {schedule_with_description}. The operation
+     * id is the same as with {schedule_batch}.
+     *
+     * Emits one {CallScheduled} event per call.
      *
      * Requirements:
      *
-     * - if the timelock is self-managed, caller can direct the timelock to grant a role. In this case,
-     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
-     * first schedule an operation where the timelock is the target. then execute the operation.
+     * - same as {schedule_batch}.
      */
-    pub fn grant_role(e: Env, account: Address, role: RoleLabel) -> bool {
-        owner::only_owner(&e);
-        role_base::grant_role(&e, &account, &role)
+    pub fn schedule_batch_with_description(
+        e: Env,
+        proposer: Address,
+        targets: Vec<Address>,
+        fn_names: Vec<Symbol>,
+        datas: Vec<Vec<Val>>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+        delay: u64,
+        description: String,
+    ) -> BytesN<32> {
+        for (target, fn_name) in targets.iter().zip(fn_names.iter()) {
+            if target == e.current_contract_address() {
+                Self::_check_fn_name(&e, &fn_name);
+            }
+        }
+
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let calls = time_lock::batch_calls(&e, &targets, &fn_names, &datas);
+        time_lock::schedule_batch_with_description(
+            &e,
+            &proposer,
+            &calls,
+            &salt,
+            &predecessor,
+            delay,
+            &description,
+        )
     }
 
     /*
-     * Revokes a role from an account.
+     * Execute an (ready) operation containing a batch of transactions.
+     *
+     * The whole batch is reverted if any of the calls fails. Returns the
+     * values returned by the calls, in order.
+     *
+     * Emits one {CallExecuted} event per call.
      *
      * Requirements:
      *
-     * - if the timelock is self-managed, caller can direct the timelock to revoke a role. In this case,
-     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
-     * first schedule an operation where the timelock is the target. then execute the operation.
+     * - the caller must have the 'executor' role, unless open execution is enabled.
+     * - the timelock must not be paused.
      */
-    pub fn revoke_role(e: Env, account: Address, role: RoleLabel) -> bool {
-        owner::only_owner(&e);
-        role_base::revoke_role(&e, &account, &role)
+    pub fn execute_batch(
+        e: Env,
+        executor: Address,
+        targets: Vec<Address>,
+        fn_names: Vec<Symbol>,
+        datas: Vec<Vec<Val>>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+    ) -> Vec<Val> {
+        Self::_check_executor(&e, &executor);
+
+        let calls = time_lock::batch_calls(&e, &targets, &fn_names, &datas);
+        time_lock::execute_batch(&e, &executor, &calls, &salt, &predecessor)
     }
 
     /*
-     * Reset the owner account.
+     * Schedule an operation containing a single transaction that can only be
+     * executed once every operation in `predecessors` is executed. With a single
+     * predecessor, the operation id is the same as with {schedule}.
+     *
+     * Emits a {CallScheduled} event.
      *
      * Requirements:
      *
-     * - if the timelock is self-managed, caller can direct the timelock to reset the owner. In this case,
-     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
-     * first schedule an operation where the timelock is the target. then execute the operation.
+     * - same as {schedule}.
      */
-    pub fn update_owner(e: Env, owner: Address) {
-        owner::only_owner(&e);
-        owner::set_owner(&e, &owner)
+    pub fn schedule_with_predecessors(
+        e: Env,
+        proposer: Address,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessors: Vec<BytesN<32>>,
+        delay: u64,
+    ) -> BytesN<32> {
+        if target == e.current_contract_address() {
+            Self::_check_fn_name(&e, &fn_name);
+        }
+
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let call = Call { target, fn_name, data };
+        time_lock::schedule_with_predecessors(&e, &proposer, &call, &salt, &predecessors, delay)
     }
 
     /*
-     * Returns the timestamp at which an operation becomes ready (0 for
-     * unset operations, 1 for done operations).
+     * Execute an (ready) operation scheduled by {schedule_with_predecessors}.
+     * Returns the value returned by the call.
+     *
+     * Emits a {CallExecuted} event.
+     *
+     * Requirements:
+     *
+     * - same as {execute}.
+     * - every operation in `predecessors` must be executed.
      */
-    pub fn get_schedule_lock_time(e: Env, operation_id: BytesN<32>) -> u64 {
-        time_lock::get_schedule_lock_time(&e, &operation_id)
+    pub fn execute_with_predecessors(
+        e: Env,
+        executor: Address,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessors: Vec<BytesN<32>>,
+    ) -> Val {
+        Self::_check_executor(&e, &executor);
+
+        let call = Call { target, fn_name, data };
+        time_lock::execute_with_predecessors(&e, &executor, &call, &salt, &predecessors)
     }
 
     /*
-     * Returns `true` if `account` has been granted `role`.
+     * Schedule an operation containing a batch of transactions that can only be
+     * executed once every operation in `predecessors` is executed.
+     *
+     * Emits one {CallScheduled} event per call.
+     *
+     * Requirements:
+     *
+     * - same as {schedule_batch}.
      */
-    pub fn has_role(e: Env, account: Address, role: RoleLabel) -> bool {
-        role_base::has_role(&e, &account, &role)
+    pub fn schedule_batch_with_predecessors(
+        e: Env,
+        proposer: Address,
+        targets: Vec<Address>,
+        fn_names: Vec<Symbol>,
+        datas: Vec<Vec<Val>>,
+        salt: BytesN<32>,
+        predecessors: Vec<BytesN<32>>,
+        delay: u64,
+    ) -> BytesN<32> {
+        for (target, fn_name) in targets.iter().zip(fn_names.iter()) {
+            if target == e.current_contract_address() {
+                Self::_check_fn_name(&e, &fn_name);
+            }
+        }
+
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let calls = time_lock::batch_calls(&e, &targets, &fn_names, &datas);
+        time_lock::schedule_batch_with_predecessors(&e, &proposer, &calls, &salt, &predecessors, delay)
     }
 
-    fn _check_role(e: &Env, account: &Address, role: &RoleLabel) {
-        if !role_base::has_role(e, account, role) {
-            panic_with_error!(e, TimeLockError::NotPermitted);
+    /*
+     * Execute an (ready) operation scheduled by {schedule_batch_with_predecessors}.
+     * Returns the values returned by the calls, in order.
+     *
+     * Emits one {CallExecuted} event per call.
+     *
+     * Requirements:
+     *
+     * - same as {execute_batch}.
+     * - every operation in `predecessors` must be executed.
+     */
+    pub fn execute_batch_with_predecessors(
+        e: Env,
+        executor: Address,
+        targets: Vec<Address>,
+        fn_names: Vec<Symbol>,
+        datas: Vec<Vec<Val>>,
+        salt: BytesN<32>,
+        predecessors: Vec<BytesN<32>>,
+    ) -> Vec<Val> {
+        Self::_check_executor(&e, &executor);
+
+        let calls = time_lock::batch_calls(&e, &targets, &fn_names, &datas);
+        time_lock::execute_batch_with_predecessors(&e, &executor, &calls, &salt, &predecessors)
+    }
+
+    /*
+     * Propose an operation containing a single transaction. The proposal counts
+     * as the caller's approval; the operation is scheduled, and its delay starts
+     * counting, once the proposal threshold is reached.
+     *
+     * Emits an {OperationApproved} event, and {CallScheduled} events once scheduled.
+     *
+     * Requirements:
+     *
+     * - the caller must have the 'proposer' role.
+     * - if the target is the timelock itself, the function must be one of the native functions.
+     * - the operation must be neither proposed nor scheduled.
+     */
+    pub fn propose(
+        e: Env,
+        proposer: Address,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+        delay: u64,
+    ) -> BytesN<32> {
+        if target == e.current_contract_address() {
+            Self::_check_fn_name(&e, &fn_name);
         }
 
-        account.require_auth();
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let call = Call { target, fn_name, data };
+        time_lock::propose(&e, &proposer, &call, &salt, &predecessor, delay)
     }
 
-    fn _check_fn_name(e: &Env, fn_name: &Symbol) {
-        let fn_name = fn_name.clone();
-        if fn_name == Symbol::new(e, "update_min_delay") {
-            return;
-        } else if fn_name == Symbol::new(e, "grant_role") {
-            return;
-        } else if fn_name == Symbol::new(e, "revoke_role") {
-            return;
-        } else if fn_name == Symbol::new(e, "update_owner") {
-            return;
-        } else {
-            panic_with_error!(e, TimeLockError::InvalidFuncName);
+    /*
+     * Propose an operation containing a batch of transactions, see {propose}.
+     *
+     * Requirements:
+     *
+     * - the caller must have the 'proposer' role.
+     * - `targets`, `fn_names` and `datas` must be non-empty and of the same length.
+     * - if a target is the timelock itself, the function must be one of the native functions.
+     */
+    pub fn propose_batch(
+        e: Env,
+        proposer: Address,
+        targets: Vec<Address>,
+        fn_names: Vec<Symbol>,
+        datas: Vec<Vec<Val>>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+        delay: u64,
+    ) -> BytesN<32> {
+        for (target, fn_name) in targets.iter().zip(fn_names.iter()) {
+            if target == e.current_contract_address() {
+                Self::_check_fn_name(&e, &fn_name);
+            }
         }
+
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let calls = time_lock::batch_calls(&e, &targets, &fn_names, &datas);
+        time_lock::propose_batch(&e, &proposer, &calls, &salt, &predecessor, delay)
     }
-}
 
-#[cfg(any(test, feature = "testutils"))]
-#[contractimpl]
-impl TimeLockController {
-    pub fn get_min_delay(e: &Env) -> u64 {
-        e.storage().instance().get(&DataKey::MinDelay).unwrap_or(0)
+    /*
+     * Propose an operation containing a single transaction that can only be
+     * executed once every operation in `predecessors` is executed, see
+     * {propose}. The operation id is the same as with {schedule_with_predecessors}.
+     *
+     * Requirements:
+     *
+     * - same as {propose}.
+     */
+    pub fn propose_with_predecessors(
+        e: Env,
+        proposer: Address,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessors: Vec<BytesN<32>>,
+        delay: u64,
+    ) -> BytesN<32> {
+        if target == e.current_contract_address() {
+            Self::_check_fn_name(&e, &fn_name);
+        }
+
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let call = Call { target, fn_name, data };
+        time_lock::propose_with_predecessors(&e, &proposer, &call, &salt, &predecessors, delay)
+    }
+
+    /*
+     * Propose an operation containing a batch of transactions that can only be
+     * executed once every operation in `predecessors` is executed, see {propose}.
+     * The operation id is the same as with {schedule_batch_with_predecessors}.
+     *
+     * Requirements:
+     *
+     * - same as {propose_batch}.
+     */
+    pub fn propose_batch_with_predecessors(
+        e: Env,
+        proposer: Address,
+        targets: Vec<Address>,
+        fn_names: Vec<Symbol>,
+        datas: Vec<Vec<Val>>,
+        salt: BytesN<32>,
+        predecessors: Vec<BytesN<32>>,
+        delay: u64,
+    ) -> BytesN<32> {
+        for (target, fn_name) in targets.iter().zip(fn_names.iter()) {
+            if target == e.current_contract_address() {
+                Self::_check_fn_name(&e, &fn_name);
+            }
+        }
+
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let calls = time_lock::batch_calls(&e, &targets, &fn_names, &datas);
+        time_lock::propose_batch_with_predecessors(&e, &proposer, &calls, &salt, &predecessors, delay)
+    }
+
+    /*
+     * Propose an operation containing a single transaction, with a description
+     * that is stored with the operation once it is scheduled, see {propose}. The
+     * operation id is the same as with {propose}.
+     *
+     * Requirements:
+     *
+     * - same as {propose}.
+     */
+    pub fn propose_with_description(
+        e: Env,
+        proposer: Address,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+        delay: u64,
+        description: String,
+    ) -> BytesN<32> {
+        if target == e.current_contract_address() {
+            Self::_check_fn_name(&e, &fn_name);
+        }
+
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let call = Call { target, fn_name, data };
+        time_lock::propose_with_description(
+            &e,
+            &proposer,
+            &call,
+            &salt,
+            &predecessor,
+            delay,
+            &description,
+        )
+    }
+
+    /*
+     * Propose an operation containing a single transaction together with the
+     * sub-invocations the timelock authorizes on its own behalf, see {propose}
+     * and {schedule_with_auth}. The auth entries are part of the operation id,
+     * and the operation must be executed with {execute_with_auth}.
+     *
+     * Requirements:
+     *
+     * - same as {propose}.
+     */
+    pub fn propose_with_auth(
+        e: Env,
+        proposer: Address,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+        delay: u64,
+        auth_entries: Vec<InvokerContractAuthEntry>,
+    ) -> BytesN<32> {
+        if target == e.current_contract_address() {
+            Self::_check_fn_name(&e, &fn_name);
+        }
+
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let call = Call { target, fn_name, data };
+        time_lock::propose_with_auth(
+            &e,
+            &proposer,
+            &call,
+            &salt,
+            &predecessor,
+            delay,
+            &auth_entries,
+        )
+    }
+
+    /*
+     * Propose an operation containing a batch of transactions, with a
+     * description, see {propose_with_description}.
+     *
+     * Requirements:
+     *
+     * - same as {propose_batch}.
+     */
+    pub fn propose_batch_with_description(
+        e: Env,
+        proposer: Address,
+        targets: Vec<Address>,
+        fn_names: Vec<Symbol>,
+        datas: Vec<Vec<Val>>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+        delay: u64,
+        description: String,
+    ) -> BytesN<32> {
+        for (target, fn_name) in targets.iter().zip(fn_names.iter()) {
+            if target == e.current_contract_address() {
+                Self::_check_fn_name(&e, &fn_name);
+            }
+        }
+
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        let calls = time_lock::batch_calls(&e, &targets, &fn_names, &datas);
+        time_lock::propose_batch_with_description(
+            &e,
+            &proposer,
+            &calls,
+            &salt,
+            &predecessor,
+            delay,
+            &description,
+        )
+    }
+
+    /*
+     * Approve a proposed operation. The operation is scheduled once the
+     * proposal threshold is reached.
+     *
+     * Emits an {OperationApproved} event, and {CallScheduled} events once scheduled.
+     *
+     * Requirements:
+     *
+     * - the caller must have the 'proposer' role.
+     * - the caller must not have approved the operation already.
+     */
+    pub fn approve(e: Env, proposer: Address, operation_id: BytesN<32>) {
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        time_lock::approve(&e, &proposer, &operation_id)
+    }
+
+    /*
+     * Withdraw an approval from a proposed operation. The proposal is dropped
+     * when its last approval is revoked.
+     *
+     * Emits an {ApprovalRevoked} event.
+     *
+     * Requirements:
+     *
+     * - the caller must have the 'proposer' role.
+     * - the caller must have approved the operation.
+     */
+    pub fn revoke_approval(e: Env, proposer: Address, operation_id: BytesN<32>) {
+        Self::_check_role(&e, &proposer, &RoleLabel::Proposer);
+
+        time_lock::revoke_approval(&e, &proposer, &operation_id)
+    }
+
+    /*
+     * Pause the timelock. While paused, operations can be neither scheduled
+     * nor executed, and the countdown of scheduled operations is frozen.
+     * Operations that only unpause the timelock are exempt.
+     *
+     * Emits a {Paused} event.
+     *
+     * Requirements:
+     *
+     * - the caller must have the 'guardian' role.
+     * - the timelock must not be paused.
+     */
+    pub fn pause(e: Env, guardian: Address) {
+        Self::_check_role(&e, &guardian, &RoleLabel::Guardian);
+
+        time_lock::pause(&e, &guardian)
+    }
+
+    /*
+     * Unpause the timelock.
+     *
+     * Emits an {Unpaused} event.
+     *
+     * Requirements:
+     *
+     * - the timelock must be paused.
+     * - if the timelock is self-managed, caller can direct the timelock to unpause. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn unpause(e: Env) {
+        Self::_only_owner(&e);
+        time_lock::unpause(&e)
+    }
+
+    /*
+     * Cancel an operation.
+     *
+     * Requirements:
+     *
+     * - the caller must have the 'canceller' role.
+     */
+    pub fn cancel(e: Env, canceller: Address, operation_id: BytesN<32>) {
+        Self::_check_role(&e, &canceller, &RoleLabel::Canceller);
+
+        time_lock::cancel(&e, &canceller, &operation_id)
+    }
+
+    /*
+     * Cancel several operations at once. Reverted if any of them cannot be
+     * cancelled.
+     *
+     * Emits an {OperationCancelled} event per operation.
+     *
+     * Requirements:
+     *
+     * - the caller must have the 'canceller' role.
+     * - `operation_ids` must not be empty.
+     */
+    pub fn cancel_batch(e: Env, canceller: Address, operation_ids: Vec<BytesN<32>>) {
+        Self::_check_role(&e, &canceller, &RoleLabel::Canceller);
+
+        time_lock::cancel_batch(&e, &canceller, &operation_ids)
+    }
+
+    /*
+     * Cancel the operation containing a single transaction with these
+     * parameters, and return its id.
+     *
+     * Emits an {OperationCancelled} event.
+     *
+     * Requirements:
+     *
+     * - the caller must have the 'canceller' role.
+     */
+    pub fn cancel_call(
+        e: Env,
+        canceller: Address,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+    ) -> BytesN<32> {
+        Self::_check_role(&e, &canceller, &RoleLabel::Canceller);
+
+        let call = Call { target, fn_name, data };
+        let operation_id = time_lock::hash_operation(&e, &call, &salt, &predecessor);
+        time_lock::cancel(&e, &canceller, &operation_id);
+        operation_id
+    }
+
+    /*
+     * Drop the record of a cancelled operation. Cancelled operations cannot be
+     * scheduled again until their record is cleared, so that a proposer cannot
+     * re-submit an operation that a canceller has just cancelled.
+     *
+     * Emits a {CancellationCleared} event.
+     *
+     * Requirements:
+     *
+     * - the caller must have the 'canceller' role.
+     * - the operation must be cancelled.
+     */
+    pub fn clear_cancellation(e: Env, canceller: Address, operation_id: BytesN<32>) {
+        Self::_check_role(&e, &canceller, &RoleLabel::Canceller);
+
+        time_lock::clear_cancellation(&e, &operation_id)
+    }
+
+    /*
+     * Changes the minimum timelock duration for future operations.
+     *
+     * Emits a {MinDelayUpdated} event.
+     *
+     * Requirements:
+     *
+     * - `delay` must not exceed `MAX_MIN_DELAY`.
+     * - if the timelock is self-managed, caller can direct the timelock to update the min delay. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn update_min_delay(e: Env, delay: u64) {
+        Self::_only_owner(&e);
+        time_lock::update_min_delay(&e, delay);
+    }
+
+    /*
+     * Grants a role to an account.
+     *
+     * Requirements:
+     *
+     * - if the timelock is self-managed, caller can direct the timelock to grant a role. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn grant_role(e: Env, account: Address, role: Symbol) -> bool {
+        Self::_only_owner(&e);
+        let sender = owner::get_owner(&e).unwrap();
+        role_base::grant_role(&e, &account, &role, &sender, &ROLE_TTL)
+    }
+
+    /*
+     * Revokes a role from an account.
+     *
+     * Requirements:
+     *
+     * - if the timelock is self-managed, caller can direct the timelock to revoke a role. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn revoke_role(e: Env, account: Address, role: Symbol) -> bool {
+        Self::_only_owner(&e);
+        let sender = owner::get_owner(&e).unwrap();
+        role_base::revoke_role(&e, &account, &role, &sender, &ROLE_TTL)
+    }
+
+    /*
+     * Sets the role whose holders can grant and revoke `role` through
+     * {grant_role_as_admin} and {revoke_role_as_admin}, on top of the owner.
+     *
+     * Emits a {RoleAdminChanged} event.
+     *
+     * Requirements:
+     *
+     * - if the timelock is self-managed, caller can direct the timelock to set the admin role. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn set_role_admin(e: Env, role: Symbol, admin_role: Symbol) {
+        Self::_only_owner(&e);
+        role_base::set_role_admin(&e, &role, &admin_role, &ROLE_TTL)
+    }
+
+    /*
+     * Grants a role to an account, on behalf of a holder of its admin role.
+     *
+     * Requirements:
+     *
+     * - `admin` must hold the admin role of `role`.
+     */
+    pub fn grant_role_as_admin(e: Env, admin: Address, account: Address, role: Symbol) -> bool {
+        role_base::only_role_admin(&e, &admin, &role, &ROLE_TTL);
+        time_lock::extend_instance_ttl(&e);
+        role_base::grant_role(&e, &account, &role, &admin, &ROLE_TTL)
+    }
+
+    /*
+     * Revokes a role from an account, on behalf of a holder of its admin role.
+     *
+     * Requirements:
+     *
+     * - `admin` must hold the admin role of `role`.
+     */
+    pub fn revoke_role_as_admin(e: Env, admin: Address, account: Address, role: Symbol) -> bool {
+        role_base::only_role_admin(&e, &admin, &role, &ROLE_TTL);
+        time_lock::extend_instance_ttl(&e);
+        role_base::revoke_role(&e, &account, &role, &admin, &ROLE_TTL)
+    }
+
+    /*
+     * Gives up a role held by the caller.
+     *
+     * Requirements:
+     *
+     * - the caller must hold `role`.
+     */
+    pub fn renounce_role(e: Env, account: Address, role: Symbol) {
+        role_base::renounce_role(&e, &account, &role, &ROLE_TTL);
+        time_lock::extend_instance_ttl(&e);
+    }
+
+    /*
+     * Nominate a new owner account. The nominee becomes the owner once it calls
+     * {accept_ownership}; until then the current owner keeps its rights.
+     *
+     * Emits an {OwnershipTransferStarted} event.
+     *
+     * Requirements:
+     *
+     * - if the timelock is self-managed, caller can direct the timelock to nominate the owner. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn update_owner(e: Env, owner: Address) {
+        Self::_only_owner(&e);
+        owner::transfer_ownership(&e, &owner)
+    }
+
+    /*
+     * Accept a pending ownership transfer.
+     *
+     * Emits an {OwnerSet} event.
+     *
+     * Requirements:
+     *
+     * - the caller must be the nominated owner.
+     */
+    pub fn accept_ownership(e: Env) {
+        owner::accept_ownership(&e);
+        time_lock::extend_instance_ttl(&e);
+    }
+
+    /*
+     * Withdraw a pending ownership nomination.
+     *
+     * Emits an {OwnershipTransferCancelled} event.
+     *
+     * Requirements:
+     *
+     * - if the timelock is self-managed, caller can direct the timelock to cancel the transfer. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn cancel_ownership_transfer(e: Env) {
+        Self::_only_owner(&e);
+        owner::cancel_ownership_transfer(&e)
+    }
+
+    /*
+     * Remove the owner account. From then on the timelock is self-managed:
+     * every administration task has to be scheduled and executed.
+     *
+     * Emits an {OwnershipRenounced} event.
+     *
+     * Requirements:
+     *
+     * - if the timelock is self-managed, caller can direct the timelock to renounce the owner. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn renounce_ownership(e: Env) {
+        Self::_only_owner(&e);
+        owner::renounce_ownership(&e)
+    }
+
+    /*
+     * Upgrade the timelock to the code uploaded under `new_wasm_hash`. The new
+     * code takes effect once this invocation returns; {migrate} should then be
+     * called to update the storage.
+     *
+     * Emits an {Upgraded} event.
+     *
+     * Requirements:
+     *
+     * - if the timelock is self-managed, caller can direct the timelock to upgrade. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn upgrade(e: Env, new_wasm_hash: BytesN<32>) {
+        Self::_only_owner(&e);
+        time_lock::upgrade(&e, &new_wasm_hash)
+    }
+
+    /*
+     * Bring the storage up to date with the current code after an upgrade.
+     * Version 1 is the first version, so no migration exists yet.
+     *
+     * Emits a {Migrated} event.
+     *
+     * Requirements:
+     *
+     * - the storage must be older than the code version.
+     * - if the timelock is self-managed, caller can direct the timelock to migrate. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn migrate(e: Env) {
+        Self::_only_owner(&e);
+        time_lock::migrate(&e)
+    }
+
+    /*
+     * Returns the version of the timelock code.
+     */
+    pub fn version(_e: Env) -> u32 {
+        time_lock::VERSION
+    }
+
+    /*
+     * Returns the version the storage was initialized or last migrated to.
+     */
+    pub fn get_storage_version(e: Env) -> u32 {
+        time_lock::get_storage_version(&e)
+    }
+
+    /*
+     * Changes the minimum delay for future operations calling `target`.
+     * The strictest of the global, target and function delays applies. 0 removes the override.
+     *
+     * Emits a {TargetMinDelayUpdated} event.
+     *
+     * Requirements:
+     *
+     * - if the timelock is self-managed, caller can direct the timelock to update the delay. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn update_target_min_delay(e: Env, target: Address, delay: u64) {
+        Self::_only_owner(&e);
+        time_lock::update_target_min_delay(&e, &target, delay);
+    }
+
+    /*
+     * Changes the minimum delay for future operations calling `fn_name` on `target`.
+     * The strictest of the global, target and function delays applies. 0 removes the override.
+     *
+     * Emits a {FnMinDelayUpdated} event.
+     *
+     * Requirements:
+     *
+     * - if the timelock is self-managed, caller can direct the timelock to update the delay. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn update_fn_min_delay(e: Env, target: Address, fn_name: Symbol, delay: u64) {
+        Self::_only_owner(&e);
+        time_lock::update_fn_min_delay(&e, &target, &fn_name, delay);
+    }
+
+    /*
+     * Changes how long, in seconds, an operation stays executable once it is ready.
+     * After that it expires and can only be cancelled. 0 disables expiry. Each
+     * operation keeps the grace period it was scheduled with.
+     *
+     * Emits a {GracePeriodUpdated} event.
+     *
+     * Requirements:
+     *
+     * - `grace_period` must not exceed `MAX_GRACE_PERIOD`.
+     * - if the timelock is self-managed, caller can direct the timelock to update the grace period. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn update_grace_period(e: Env, grace_period: u64) {
+        Self::_only_owner(&e);
+        time_lock::update_grace_period(&e, grace_period);
+    }
+
+    /*
+     * Enables or disables open execution. When enabled, anyone can execute
+     * ready operations without holding the 'executor' role; the ready and
+     * predecessor checks still apply.
+     *
+     * Emits an {OpenExecutionUpdated} event.
+     *
+     * Requirements:
+     *
+     * - if the timelock is self-managed, caller can direct the timelock to update the setting. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn update_open_execution(e: Env, open: bool) {
+        Self::_only_owner(&e);
+        time_lock::update_open_execution(&e, open);
+    }
+
+    /*
+     * Changes the number of ledgers an operation is kept alive for on top of its delay.
+     *
+     * Emits a {TtlMarginUpdated} event.
+     *
+     * Requirements:
+     *
+     * - if the timelock is self-managed, caller can direct the timelock to update the margin. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn update_ttl_margin(e: Env, margin: u32) {
+        Self::_only_owner(&e);
+        time_lock::update_ttl_margin(&e, margin);
+    }
+
+    /*
+     * Changes the number of distinct proposers that must approve an operation
+     * before it is scheduled. Pending proposals are not re-evaluated.
+     *
+     * Emits a {ProposalThresholdUpdated} event.
+     *
+     * Requirements:
+     *
+     * - `threshold` must be at least 1.
+     * - if the timelock is self-managed, caller can direct the timelock to update the threshold. In this case,
+     * the timelock will check that the caller is the owner. If the timelock is not self-managed, the caller must
+     * first schedule an operation where the timelock is the target. then execute the operation.
+     */
+    pub fn update_proposal_threshold(e: Env, threshold: u32) {
+        Self::_only_owner(&e);
+        time_lock::update_proposal_threshold(&e, threshold);
+    }
+
+    /*
+     * Extends the TTL of an operation so that it cannot be archived before it
+     * is ready. Anyone can call this function.
+     */
+    pub fn bump_operation(e: Env, operation_id: BytesN<32>) {
+        time_lock::bump_operation(&e, &operation_id);
+    }
+
+    /*
+     * Removes the expired operations among the `limit` indexes from `start`
+     * from the pending index, and returns how many were removed. Pruned
+     * operations can still be cancelled. Anyone can call this function.
+     */
+    pub fn prune_expired_operations(e: Env, start: u32, limit: u32) -> u32 {
+        time_lock::prune_expired_operations(&e, start, limit)
+    }
+
+    /*
+     * Extends the TTL of the contract instance. Anyone can call this function.
+     */
+    pub fn bump_instance(e: Env) {
+        time_lock::extend_instance_ttl(&e);
+    }
+
+    /*
+     * Returns the timestamp at which an operation becomes ready (0 for
+     * unset operations, 1 for done operations).
+     */
+    pub fn get_schedule_lock_time(e: Env, operation_id: BytesN<32>) -> u64 {
+        time_lock::get_schedule_lock_time(&e, &operation_id)
+    }
+
+    /*
+     * Returns the minimum delay an operation calling `fn_name` on `target` must be scheduled with.
+     */
+    pub fn get_min_delay_for(e: Env, target: Address, fn_name: Symbol) -> u64 {
+        time_lock::get_min_delay_for(&e, &target, &fn_name)
+    }
+
+    /*
+     * Returns the record of an operation, or `None` if it has never been scheduled.
+     *
+     * Cancelled operations are kept with the `Cancelled` status.
+     */
+    pub fn get_operation(e: Env, operation_id: BytesN<32>) -> Option<Operation> {
+        time_lock::get_operation(&e, &operation_id)
+    }
+
+    /*
+     * Returns a proposal waiting for approvals, or `None` if there is none.
+     */
+    pub fn get_proposal(e: Env, operation_id: BytesN<32>) -> Option<Proposal> {
+        time_lock::get_proposal(&e, &operation_id)
+    }
+
+    /*
+     * Returns the number of approvals an operation needs before it is scheduled.
+     */
+    pub fn get_proposal_threshold(e: Env) -> u32 {
+        time_lock::get_proposal_threshold(&e)
+    }
+
+    /*
+     * Returns the pending operations, see {is_operation_pending}, among the
+     * `limit` indexes from `start`, with the time at which they become ready.
+     * Indexes follow the scheduling order; finished and expired operations are
+     * skipped, so fewer than `limit` operations may be returned.
+     */
+    pub fn get_pending_operations(e: Env, start: u32, limit: u32) -> Vec<PendingOperation> {
+        time_lock::get_pending_operations(&e, start, limit)
+    }
+
+    /*
+     * Returns the number of indexes handed out to scheduled operations so far.
+     */
+    pub fn get_pending_index_len(e: Env) -> u32 {
+        time_lock::get_pending_index_len(&e)
+    }
+
+    /*
+     * Same as `get_pending_operations`, only returning the operations that can
+     * be executed now.
+     */
+    pub fn get_ready_operations(e: Env, start: u32, limit: u32) -> Vec<PendingOperation> {
+        time_lock::get_ready_operations(&e, start, limit)
+    }
+
+    /*
+     * Returns the id of the operation containing a single transaction with these
+     * parameters, as returned by {schedule}. Ids are bound to this timelock and
+     * network, so they differ between instances.
+     */
+    pub fn hash_operation(
+        e: Env,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+    ) -> BytesN<32> {
+        let call = Call { target, fn_name, data };
+        time_lock::hash_operation(&e, &call, &salt, &predecessor)
+    }
+
+    /*
+     * Returns the id of the operation scheduled by {schedule_with_predecessors}
+     * with these parameters.
+     */
+    pub fn hash_operation_with_predecessors(
+        e: Env,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessors: Vec<BytesN<32>>,
+    ) -> BytesN<32> {
+        let call = Call { target, fn_name, data };
+        time_lock::hash_operation_with_predecessors(&e, &call, &salt, &predecessors)
+    }
+
+    /*
+     * Returns the id of the operation scheduled by {schedule_with_auth} with
+     * these parameters.
+     */
+    pub fn hash_operation_with_auth(
+        e: Env,
+        target: Address,
+        fn_name: Symbol,
+        data: Vec<Val>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+        auth_entries: Vec<InvokerContractAuthEntry>,
+    ) -> BytesN<32> {
+        let call = Call { target, fn_name, data };
+        time_lock::hash_operation_with_auth(&e, &call, &salt, &predecessor, &auth_entries)
+    }
+
+    /*
+     * Returns the id of the batch operation scheduled by {schedule_batch} with
+     * these parameters.
+     *
+     * Requirements:
+     *
+     * - `targets`, `fn_names` and `datas` must have the same length.
+     */
+    pub fn hash_operation_batch(
+        e: Env,
+        targets: Vec<Address>,
+        fn_names: Vec<Symbol>,
+        datas: Vec<Vec<Val>>,
+        salt: BytesN<32>,
+        predecessor: Option<BytesN<32>>,
+    ) -> BytesN<32> {
+        let calls = time_lock::batch_calls(&e, &targets, &fn_names, &datas);
+        time_lock::hash_operation_batch(&e, &calls, &salt, &predecessor)
+    }
+
+    /*
+     * Returns the id of the batch operation scheduled by
+     * {schedule_batch_with_predecessors} with these parameters.
+     */
+    pub fn hash_batch_with_predecessors(
+        e: Env,
+        targets: Vec<Address>,
+        fn_names: Vec<Symbol>,
+        datas: Vec<Vec<Val>>,
+        salt: BytesN<32>,
+        predecessors: Vec<BytesN<32>>,
+    ) -> BytesN<32> {
+        let calls = time_lock::batch_calls(&e, &targets, &fn_names, &datas);
+        time_lock::hash_operation_batch_with_predecessors(&e, &calls, &salt, &predecessors)
+    }
+
+    /*
+     * Returns the state of an operation: Unset, Waiting, Ready, Executed or Expired.
+     */
+    pub fn get_operation_state(e: Env, operation_id: BytesN<32>) -> OperationState {
+        time_lock::get_operation_state(&e, &operation_id)
+    }
+
+    /*
+     * Returns `true` if an operation is scheduled and neither executed,
+     * cancelled nor expired, whether or not it is ready. This is the meaning of
+     * pending throughout the timelock, including {get_pending_operations}.
+     */
+    pub fn is_operation_pending(e: Env, operation_id: BytesN<32>) -> bool {
+        time_lock::is_operation_pending(&e, &operation_id)
+    }
+
+    /*
+     * Returns `true` if an operation is ready for execution.
+     */
+    pub fn is_operation_ready(e: Env, operation_id: BytesN<32>) -> bool {
+        time_lock::get_operation_state(&e, &operation_id) == OperationState::Ready
+    }
+
+    /*
+     * Returns `true` if an operation has been executed.
+     */
+    pub fn is_operation_done(e: Env, operation_id: BytesN<32>) -> bool {
+        time_lock::get_operation_state(&e, &operation_id) == OperationState::Executed
+    }
+
+    /*
+     * Returns the nominated owner, or `None` if no transfer is pending.
+     */
+    pub fn get_pending_owner(e: Env) -> Option<Address> {
+        owner::get_pending_owner(&e)
+    }
+
+    /*
+     * Returns `true` if the timelock is paused.
+     */
+    pub fn is_paused(e: Env) -> bool {
+        time_lock::is_paused(&e)
+    }
+
+    /*
+     * Returns `true` if anyone can execute ready operations.
+     */
+    pub fn is_open_execution(e: Env) -> bool {
+        time_lock::is_open_execution(&e)
+    }
+
+    /*
+     * Returns `true` if `account` has been granted `role`.
+     */
+    pub fn has_role(e: Env, account: Address, role: Symbol) -> bool {
+        role_base::has_role(&e, &account, &role)
+    }
+
+    /*
+     * Returns the admin role of `role`, or `None` if only the owner manages it.
+     */
+    pub fn get_role_admin(e: Env, role: Symbol) -> Option<Symbol> {
+        role_base::get_role_admin(&e, &role, &ROLE_TTL)
+    }
+
+    /*
+     * Returns the accounts holding `role`.
+     */
+    pub fn get_role_members(e: Env, role: Symbol) -> Vec<Address> {
+        role_base::get_role_members(&e, &role)
+    }
+
+    /*
+     * Returns the number of accounts holding `role`.
+     */
+    pub fn get_role_member_count(e: Env, role: Symbol) -> u32 {
+        role_base::get_role_member_count(&e, &role)
+    }
+
+    /*
+     * Returns one of the accounts holding `role`. `index` must be lower than
+     * {get_role_member_count}; the order may change when the role is revoked.
+     */
+    pub fn get_role_member(e: Env, role: Symbol, index: u32) -> Address {
+        match role_base::get_role_member(&e, &role, index) {
+            Some(account) => account,
+            None => panic_with_error!(e, TimeLockError::InvalidParams),
+        }
+    }
+
+    fn _check_role(e: &Env, account: &Address, role: &RoleLabel) {
+        let role = role.to_symbol();
+        if !role_base::has_role(e, account, &role) {
+            panic_with_error!(e, TimeLockError::NotPermitted);
+        }
+
+        account.require_auth();
+
+        role_base::extend_role_ttl(e, account, &role, &ROLE_TTL);
+        time_lock::extend_instance_ttl(e);
+    }
+
+    fn _check_executor(e: &Env, executor: &Address) {
+        if time_lock::is_open_execution(e) {
+            executor.require_auth();
+            time_lock::extend_instance_ttl(e);
+        } else {
+            Self::_check_role(e, executor, &RoleLabel::Executor);
+        }
+    }
+
+    fn _only_owner(e: &Env) {
+        owner::only_owner(e);
+        time_lock::extend_instance_ttl(e);
+    }
+
+    fn _check_fn_name(e: &Env, fn_name: &Symbol) {
+        if !time_lock::is_native_function(e, fn_name) {
+            panic_with_error!(e, TimeLockError::InvalidFuncName);
+        }
+    }
+}
+
+#[cfg(any(test, feature = "testutils"))]
+#[contractimpl]
+impl TimeLockController {
+    pub fn get_min_delay(e: &Env) -> u64 {
+        e.storage().instance().get(&DataKey::MinDelay).unwrap_or(0)
+    }
+
+    pub fn get_grace_period(e: &Env) -> u64 {
+        time_lock::get_grace_period(e)
+    }
+
+    pub fn get_ttl_margin(e: &Env) -> u32 {
+        time_lock::get_ttl_margin(e)
     }
 
     pub fn is_owner(e: &Env, account: Address) -> bool {
-        owner::get_owner(e).map_or(false, |owner| owner == account)
+        owner::get_owner(e) == Some(account)
     }
 }
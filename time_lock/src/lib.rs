@@ -1,7 +1,6 @@
 #![no_std]
 
 mod contract;
-mod role_base;
 mod time_lock;
 
 #[cfg(any(test, feature = "testutils"))]
@@ -9,9 +8,16 @@ pub mod test {
 
     pub use crate::contract::{TimeLockController, TimeLockControllerClient};
 
-    pub use crate::time_lock::{CallExecutedEvent, CallScheduledEvent, DataKey, TimeLockError};
+    pub use crate::time_lock::{
+        Call, CallExecutedEvent, CallFailedEvent, CallScheduledEvent, CancelledEvent, DataKey,
+        ExecutionFailure, MinDelayChangedEvent, Operation, OperationExecutor, OperationState,
+        OperationStatus, PendingOperation, Proposal, RoleLabel, TimeLockError,
+    };
 
-    pub use crate::role_base::{ RoleKey, RoleLabel};
+    pub use role_base::role_base::{RoleError, RoleGrantedEvent, RoleKey, RoleRevokedEvent};
 
-    pub use owner::owner::OwnerError;
+    pub use owner::owner::{
+        OwnerChangedEvent, OwnerError, OwnershipRenouncedEvent, OwnershipTransferStartedEvent,
+        PreviousOwner,
+    };
 }
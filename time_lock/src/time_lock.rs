@@ -1,34 +1,94 @@
-use soroban_sdk::InvokeError;
 use soroban_sdk::{
-    contracterror, contracttype, panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol,
-    TryFromVal, Val, Vec,
+    auth::InvokerContractAuthEntry, contracterror, contracttype, panic_with_error, symbol_short,
+    xdr::{ScErrorType, ToXdr},
+    Address, Bytes, BytesN, Env, Error, IntoVal, String, Symbol, TryFromVal, Val, Vec,
 };
 
 use core::primitive::u64;
 
-use crate::role_base;
-use crate::role_base::RoleLabel;
 use owner::owner;
+use role_base::role_base;
+use role_base::RoleTtl;
 
 const DONE_TIMESTAMP: u64 = 1;
+const OPERATION_ID_DOMAIN: &[u8] = b"TimeLockOperation/v1";
+pub const VERSION: u32 = 1;
 pub const MAX_MIN_DELAY: u64 = 30 * 24 * 60 * 60; // 30 days
+pub const MAX_GRACE_PERIOD: u64 = 30 * 24 * 60 * 60; // 30 days
+
+const LEDGER_SECONDS: u64 = 5;
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+const DEFAULT_TTL_MARGIN: u32 = 7 * DAY_IN_LEDGERS;
+
+/*
+ * Entries every operation relies on, such as the instance and the roles, are
+ * kept alive long enough for an operation scheduled with the longest minimum
+ * delay to be executed at the end of the longest grace period, plus the
+ * default TTL margin. They are extended whenever less than that time is left.
+ */
+pub(crate) const TTL_THRESHOLD: u32 = ((MAX_MIN_DELAY + MAX_GRACE_PERIOD) / LEDGER_SECONDS) as u32;
+pub(crate) const TTL_EXTEND_TO: u32 = TTL_THRESHOLD + DEFAULT_TTL_MARGIN;
+pub(crate) const ROLE_TTL: RoleTtl = RoleTtl {
+    threshold: TTL_THRESHOLD,
+    extend_to: TTL_EXTEND_TO,
+};
 
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     Scheduler(BytesN<32>),
     MinDelay,
+    TargetMinDelay(Address),
+    FnMinDelay(Address, Symbol),
+    GracePeriod,
     Initialized,
+    TtlMargin,
+    ProposalThreshold,
+    Proposal(BytesN<32>),
+    OpenExecution,
+    PausedAt,
+    PausedDuration,
+    Version,
+    PendingLen,
+    PendingAt(u32),
+    PendingIndex(BytesN<32>),
+}
+
+/*
+ * Roles predefined by the timelock. Roles are plain `Symbol`s, so other
+ * roles can be granted as well; these are the ones the timelock checks.
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+#[repr(u8)]
+pub enum RoleLabel {
+    Proposer = 1,
+    Executor = 2,
+    Canceller = 3,
+    Guardian = 4,
 }
 
+impl RoleLabel {
+    pub fn to_symbol(&self) -> Symbol {
+        match self {
+            RoleLabel::Proposer => symbol_short!("proposer"),
+            RoleLabel::Executor => symbol_short!("executor"),
+            RoleLabel::Canceller => symbol_short!("canceller"),
+            RoleLabel::Guardian => symbol_short!("guardian"),
+        }
+    }
+}
+
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 #[repr(u8)]
-enum OperationState {
+pub enum OperationState {
     Unset = 1,
     Waiting = 2,
     Ready = 3,
     Executed = 4,
+    Expired = 5,
 }
 
 #[derive(Copy, Clone)]
@@ -47,26 +107,223 @@ pub enum TimeLockError {
     ExecuteFailed = 10,
     InvalidFuncName = 11,
     DelayTooLong = 12,
+    OperationExpired = 13,
+    ApprovalRequired = 14,
+    AlreadyApproved = 15,
+    NotApproved = 16,
+    Paused = 17,
+    NotPaused = 18,
+    OperationCancelled = 19,
+    GracePeriodTooLong = 20,
 }
 
+/*
+ * `result` holds the value returned by the call as its only element, a bare
+ * `Val` cannot be a field of a contract type.
+ */
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct CallExecutedEvent {
     pub opt_id: BytesN<32>,
+    pub index: u32,
     pub target: Address,
     pub fn_name: Symbol,
     pub data: Vec<Val>,
+    pub result: Vec<Val>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct CallScheduledEvent {
     pub opt_id: BytesN<32>,
+    pub index: u32,
+    pub target: Address,
+    pub fn_name: Symbol,
+    pub data: Vec<Val>,
+    pub predecessors: Vec<BytesN<32>>,
+    pub delay: u64,
+    pub description: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MinDelayChangedEvent {
+    pub old: u64,
+    pub new: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CancelledEvent {
+    pub opt_id: BytesN<32>,
+    pub canceller: Address,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CallFailedEvent {
+    pub opt_id: BytesN<32>,
+    pub index: u32,
+    pub target: Address,
+    pub fn_name: Symbol,
+    pub failure: ExecutionFailure,
+}
+
+/*
+ * Reason a call failed: the error code returned by the target contract, or
+ * the type and code of a host error, such as a panic in the target.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ExecutionFailure {
+    None,
+    Contract(u32),
+    Host(u32, u32),
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+#[repr(u8)]
+pub enum OperationStatus {
+    Pending = 1,
+    Executed = 2,
+    Cancelled = 3,
+}
+
+/*
+ * Account that executed an operation. This stands for `Option<Address>`,
+ * which soroban-sdk 20 cannot convert to an `ScVal` as a field of a contract
+ * type when `testutils` is enabled.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum OperationExecutor {
+    None,
+    Account(Address),
+}
+
+impl OperationExecutor {
+    pub fn into_option(self) -> Option<Address> {
+        match self {
+            OperationExecutor::None => None,
+            OperationExecutor::Account(account) => Some(account),
+        }
+    }
+}
+
+impl From<Option<Address>> for OperationExecutor {
+    fn from(account: Option<Address>) -> Self {
+        match account {
+            Some(account) => OperationExecutor::Account(account),
+            None => OperationExecutor::None,
+        }
+    }
+}
+
+/*
+ * Record of a scheduled operation, kept in persistent storage under
+ * `DataKey::Scheduler(operation_id)`.
+ *
+ * `targets` and `fn_names` hold one entry per call, single call operations
+ * have exactly one. `args_hash` is the sha256 of the XDR encoded call data.
+ * `predecessors` lists the operations that must be executed first, and
+ * timestamps that have not been reached yet (`executed_at`, `cancelled_at`)
+ * are 0.
+ *
+ * `ready_at` does not account for pauses: `paused_offset` is the total paused
+ * time when the operation was scheduled, and the operation becomes ready
+ * once as much unpaused time as its delay has passed.
+ *
+ * `grace_period` is the grace period when the operation was scheduled, the
+ * operation expires that long after it becomes ready, 0 meaning never. Later
+ * changes of the timelock's grace period do not apply to it, so an expired
+ * operation stays expired.
+ *
+ * `last_failure` records why the last `attempt_execute` of the operation
+ * failed, it is `ExecutionFailure::None` until then.
+ *
+ * `description` is the free text given by the proposer, empty when none was
+ * given. It is not part of the operation id.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Operation {
+    pub proposer: Address,
+    pub targets: Vec<Address>,
+    pub fn_names: Vec<Symbol>,
+    pub args_hash: BytesN<32>,
+    pub predecessors: Vec<BytesN<32>>,
+    pub scheduled_at: u64,
+    pub ready_at: u64,
+    pub paused_offset: u64,
+    pub grace_period: u64,
+    pub executed_at: u64,
+    pub cancelled_at: u64,
+    pub executor: OperationExecutor,
+    pub status: OperationStatus,
+    pub last_failure: ExecutionFailure,
+    pub description: String,
+}
+
+/*
+ * Entry of the pending operations index: the operation id and the time at
+ * which it becomes ready, pushed back by pauses.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingOperation {
+    pub opt_id: BytesN<32>,
+    pub ready_at: u64,
+}
+
+/*
+ * A single invocation of an operation: `fn_name` of `target` called with
+ * `data`.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Call {
     pub target: Address,
     pub fn_name: Symbol,
     pub data: Vec<Val>,
-    pub predecessor: BytesN<32>,
+}
+
+/*
+ * Operation waiting for enough proposers to approve it, kept in persistent
+ * storage under `DataKey::Proposal(operation_id)` until the threshold is
+ * reached and the operation is scheduled. Approvals of accounts that no
+ * longer hold the proposer role are not counted. `description` is stored with
+ * the operation once it is scheduled. `auth_entries` are the authorizations
+ * hashed into the operation id, to be passed again to `execute_with_auth`.
+ */
+#[derive(Clone, Eq, PartialEq)]
+#[contracttype]
+pub struct Proposal {
+    pub proposer: Address,
+    pub calls: Vec<Call>,
+    pub predecessors: Vec<BytesN<32>>,
     pub delay: u64,
+    pub description: String,
+    pub auth_entries: Vec<InvokerContractAuthEntry>,
+    pub approvals: Vec<Address>,
+}
+
+/*
+ * `InvokerContractAuthEntry` does not implement `Debug`, only the number of
+ * auth entries is shown.
+ */
+impl core::fmt::Debug for Proposal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Proposal")
+            .field("proposer", &self.proposer)
+            .field("calls", &self.calls)
+            .field("predecessors", &self.predecessors)
+            .field("delay", &self.delay)
+            .field("description", &self.description)
+            .field("auth_entries", &self.auth_entries.len())
+            .field("approvals", &self.approvals)
+            .finish()
+    }
 }
 
 pub(crate) fn initialize(
@@ -74,233 +331,1803 @@ pub(crate) fn initialize(
     min_delay: u64,
     proposers: &Vec<Address>,
     executors: &Vec<Address>,
+    cancellers: &Option<Vec<Address>>,
+    guardians: &Option<Vec<Address>>,
     owner:  &Option<Address>,
 ) {
     _initialize(e);
+    extend_instance_ttl(e);
+    e.storage().instance().set(&DataKey::Version, &VERSION);
 
     if min_delay > MAX_MIN_DELAY {
         panic_with_error!(e, TimeLockError::DelayTooLong);
     }
 
+    // when no cancellers are given, proposers can cancel as before
+    let cancellers = cancellers.clone().unwrap_or(proposers.clone());
+    let guardians = guardians.clone().unwrap_or(Vec::new(e));
+
+    _check_no_duplicates(e, proposers);
+    _check_no_duplicates(e, executors);
+    _check_no_duplicates(e, &cancellers);
+    _check_no_duplicates(e, &guardians);
+
     update_min_delay(e, min_delay);
 
     if let Some(owner) = owner {
         owner::set_owner(e, owner);
     } 
 
+    // initial roles are granted by the timelock itself
+    let sender = e.current_contract_address();
     for proposer in proposers.iter() {
-        role_base::grant_role(e, &proposer, &RoleLabel::Proposer);
-        role_base::grant_role(e, &proposer, &RoleLabel::Canceller);
+        role_base::grant_role(e, &proposer, &RoleLabel::Proposer.to_symbol(), &sender, &ROLE_TTL);
+    }
+
+    for canceller in cancellers.iter() {
+        role_base::grant_role(e, &canceller, &RoleLabel::Canceller.to_symbol(), &sender, &ROLE_TTL);
     }
 
     for executor in executors.iter() {
-        role_base::grant_role(e, &executor, &RoleLabel::Executor);
+        role_base::grant_role(e, &executor, &RoleLabel::Executor.to_symbol(), &sender, &ROLE_TTL);
+    }
+
+    for guardian in guardians.iter() {
+        role_base::grant_role(e, &guardian, &RoleLabel::Guardian.to_symbol(), &sender, &ROLE_TTL);
     }
 }
 
 pub(crate) fn schedule(
     e: &Env,
-    target: &Address,
-    fn_name: &Symbol,
-    data: &Vec<Val>,
+    proposer: &Address,
+    call: &Call,
     salt: &BytesN<32>,
     predecessor: &Option<BytesN<32>>,
     delay: u64,
 ) -> BytesN<32> {
-    if !_is_contract(e, target) {
-        panic_with_error!(e, TimeLockError::InvalidParams);
-    }
+    schedule_with_auth(e, proposer, call, salt, predecessor, delay, &Vec::new(e))
+}
 
-    let min_delay = e.storage().instance().get(&DataKey::MinDelay).unwrap();
-    if delay < min_delay {
-        panic_with_error!(e, TimeLockError::InsufficientDelay);
-    }
+/*
+ * Schedules a single call that can only be executed once every operation in
+ * `predecessors` is executed. With a single predecessor, the operation id is
+ * the same as when scheduling with `schedule`.
+ */
+pub(crate) fn schedule_with_predecessors(
+    e: &Env,
+    proposer: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+    delay: u64,
+) -> BytesN<32> {
+    let operation_id = _hash_call(e, call, salt, predecessors, &Vec::new(e));
+    _schedule_call(
+        e,
+        &operation_id,
+        proposer,
+        call,
+        predecessors,
+        delay,
+        &String::from_str(e, ""),
+    )
+}
 
-    let operation_id = _hash_call(e, target, fn_name, data, salt, predecessor);
-    _add_operation(e, &operation_id, delay);
+/*
+ * Schedules a single call with a description for reviewers, stored with the
+ * operation and included in the {CallScheduled} event. The description is
+ * not part of the operation id.
+ */
+pub(crate) fn schedule_with_description(
+    e: &Env,
+    proposer: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+    delay: u64,
+    description: &String,
+) -> BytesN<32> {
+    let predecessors = _predecessors(e, predecessor);
+    let operation_id = _hash_call(e, call, salt, &predecessors, &Vec::new(e));
+    _schedule_call(e, &operation_id, proposer, call, &predecessors, delay, description)
+}
 
-    let actual_predecessor = match predecessor {
-        Some(predecessor) => predecessor.clone(),
-        None => BytesN::from_array(e, &[0_u8; 32]),
-    };
+/*
+ * Schedules a single call together with the sub-invocations the timelock
+ * authorizes on its own behalf when the call is executed. Non-empty
+ * `auth_entries` are part of the operation id.
+ */
+pub(crate) fn schedule_with_auth(
+    e: &Env,
+    proposer: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+    delay: u64,
+    auth_entries: &Vec<InvokerContractAuthEntry>,
+) -> BytesN<32> {
+    let predecessors = _predecessors(e, predecessor);
+    let operation_id = _hash_call(e, call, salt, &predecessors, auth_entries);
+    _schedule_call(
+        e,
+        &operation_id,
+        proposer,
+        call,
+        &predecessors,
+        delay,
+        &String::from_str(e, ""),
+    )
+}
 
-    e.events().publish(
-        (Symbol::new(e, "CallScheduled"),),
-        CallScheduledEvent {
-            opt_id: operation_id.clone(),
-            target: target.clone(),
-            fn_name: fn_name.clone(),
-            data: data.clone(),
-            predecessor: actual_predecessor,
-            delay,
-        },
-    );
+pub(crate) fn schedule_batch(
+    e: &Env,
+    proposer: &Address,
+    calls: &Vec<Call>,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+    delay: u64,
+) -> BytesN<32> {
+    schedule_batch_with_predecessors(
+        e,
+        proposer,
+        calls,
+        salt,
+        &_predecessors(e, predecessor),
+        delay,
+    )
+}
 
-    operation_id
+pub(crate) fn schedule_batch_with_predecessors(
+    e: &Env,
+    proposer: &Address,
+    calls: &Vec<Call>,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+    delay: u64,
+) -> BytesN<32> {
+    _schedule_batch(
+        e,
+        proposer,
+        calls,
+        salt,
+        predecessors,
+        delay,
+        &String::from_str(e, ""),
+    )
 }
 
-pub(crate) fn execute(
+/*
+ * Same as `schedule_with_description`, for a batch of calls.
+ */
+pub(crate) fn schedule_batch_with_description(
     e: &Env,
-    target: &Address,
-    fn_name: &Symbol,
-    data: &Vec<Val>,
+    proposer: &Address,
+    calls: &Vec<Call>,
     salt: &BytesN<32>,
     predecessor: &Option<BytesN<32>>,
-    is_native: bool,
-) {
-    let operation_id = _hash_call(e, target, fn_name, data, salt, predecessor);
-    _check_execute(e, &operation_id, predecessor);
-
-    if is_native {
-        _exec_native(e, fn_name, data);
-    } else {
-        _exec_external(e, target, fn_name, data);
-    }
+    delay: u64,
+    description: &String,
+) -> BytesN<32> {
+    _schedule_batch(
+        e,
+        proposer,
+        calls,
+        salt,
+        &_predecessors(e, predecessor),
+        delay,
+        description,
+    )
+}
 
-    e.storage()
-        .persistent()
-        .set(&DataKey::Scheduler(operation_id.clone()), &DONE_TIMESTAMP);
+pub(crate) fn execute(
+    e: &Env,
+    executor: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+) -> Val {
+    execute_with_auth(e, executor, call, salt, predecessor, &Vec::new(e))
+}
 
-    e.events().publish(
-        (Symbol::new(e, "CallExecuted"),),
-        CallExecutedEvent {
-            opt_id: operation_id,
-            target: target.clone(),
-            fn_name: fn_name.clone(),
-            data: data.clone(),
-        },
-    );
+pub(crate) fn execute_with_auth(
+    e: &Env,
+    executor: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+    auth_entries: &Vec<InvokerContractAuthEntry>,
+) -> Val {
+    _execute_call(
+        e,
+        executor,
+        call,
+        salt,
+        &_predecessors(e, predecessor),
+        auth_entries,
+    )
 }
 
-pub(crate) fn cancel(e: &Env, operation_id: &BytesN<32>) {
-    let state = _get_operation_state(e, operation_id);
-    if state == OperationState::Ready || state == OperationState::Waiting {
-        e.storage()
-            .persistent()
-            .remove(&DataKey::Scheduler(operation_id.clone()));
-    } else {
-        panic_with_error!(e, TimeLockError::InvalidStatus);
-    }
+pub(crate) fn execute_with_predecessors(
+    e: &Env,
+    executor: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+) -> Val {
+    _execute_call(e, executor, call, salt, predecessors, &Vec::new(e))
+}
 
-    e.events().publish(
-        (Symbol::new(e, "OperationCancelled"),),
-        operation_id.clone(),
-    );
+pub(crate) fn execute_batch(
+    e: &Env,
+    executor: &Address,
+    calls: &Vec<Call>,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+) -> Vec<Val> {
+    execute_batch_with_predecessors(e, executor, calls, salt, &_predecessors(e, predecessor))
 }
 
-pub(crate) fn update_min_delay(e: &Env, delay: u64) {
-    e.storage().instance().set(&DataKey::MinDelay, &delay);
-    e.events()
-        .publish((Symbol::new(e, "MinDelayUpdated"),), delay);
+pub(crate) fn execute_batch_with_predecessors(
+    e: &Env,
+    executor: &Address,
+    calls: &Vec<Call>,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+) -> Vec<Val> {
+    let operation_id = _hash_call_batch(e, calls, salt, predecessors);
+    _execute_calls(e, &operation_id, executor, calls, predecessors, &Vec::new(e))
 }
 
-pub(crate) fn get_schedule_lock_time(e: &Env, operation_id: &BytesN<32>) -> u64 {
-    let key = DataKey::Scheduler(operation_id.clone());
-    if let Some(schedule) = e.storage().persistent().get::<DataKey, u64>(&key) {
-        schedule
+/*
+ * Executes a ready operation containing a single call without reverting
+ * when the target fails. The failure is recorded on the operation, which
+ * stays pending, and `false` is returned.
+ */
+pub(crate) fn attempt_execute(
+    e: &Env,
+    executor: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+) -> bool {
+    let predecessors = _predecessors(e, predecessor);
+    let operation_id = _hash_call(e, call, salt, &predecessors, &Vec::new(e));
+    _check_not_paused(e, &Vec::from_array(e, [call.clone()]));
+    _check_execute(e, &operation_id, &predecessors);
+
+    let result = if call.target == e.current_contract_address() {
+        Ok(_exec_native(e, &call.fn_name, &call.data))
     } else {
-        0_u64
+        _try_exec_external(e, call)
+    };
+
+    match result {
+        Ok(result) => {
+            e.events().publish(
+                (Symbol::new(e, "CallExecuted"),),
+                CallExecutedEvent {
+                    opt_id: operation_id.clone(),
+                    index: 0,
+                    target: call.target.clone(),
+                    fn_name: call.fn_name.clone(),
+                    data: call.data.clone(),
+                    result: Vec::from_array(e, [result]),
+                },
+            );
+            _mark_executed(e, &operation_id, executor);
+            true
+        }
+        Err(failure) => {
+            let mut operation = get_operation(e, &operation_id).unwrap();
+            operation.last_failure = failure.clone();
+            e.storage()
+                .persistent()
+                .set(&DataKey::Scheduler(operation_id.clone()), &operation);
+            e.events().publish(
+                (Symbol::new(e, "CallFailed"),),
+                CallFailedEvent {
+                    opt_id: operation_id,
+                    index: 0,
+                    target: call.target.clone(),
+                    fn_name: call.fn_name.clone(),
+                    failure,
+                },
+            );
+            false
+        }
     }
 }
 
-fn _get_operation_state(e: &Env, operation_id: &BytesN<32>) -> OperationState {
-    let ledger_time = e.ledger().timestamp();
-    let lock_time = get_schedule_lock_time(e, operation_id);
-    if lock_time == 0 {
-        OperationState::Unset
-    } else if lock_time == DONE_TIMESTAMP {
-        OperationState::Executed
-    } else if ledger_time < lock_time {
-        OperationState::Waiting
-    } else {
-        OperationState::Ready
-    }
+pub(crate) fn propose(
+    e: &Env,
+    proposer: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+    delay: u64,
+) -> BytesN<32> {
+    propose_with_predecessors(e, proposer, call, salt, &_predecessors(e, predecessor), delay)
 }
 
-fn _add_operation(e: &Env, operation_id: &BytesN<32>, delay: u64) {
-    let ledger_time = e.ledger().timestamp();
-    if _get_operation_state(e, operation_id) != OperationState::Unset {
-        panic_with_error!(e, TimeLockError::AlreadyExists);
-    }
+/*
+ * Same as `propose`, for an operation that can only be executed once every
+ * operation in `predecessors` is executed.
+ */
+pub(crate) fn propose_with_predecessors(
+    e: &Env,
+    proposer: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+    delay: u64,
+) -> BytesN<32> {
+    let operation_id = _hash_call(e, call, salt, predecessors, &Vec::new(e));
+    let proposal = _proposal(
+        e,
+        proposer,
+        &Vec::from_array(e, [call.clone()]),
+        predecessors,
+        delay,
+        &String::from_str(e, ""),
+        &Vec::new(e),
+    );
+    _propose_calls(e, &operation_id, &proposal);
 
-    let time = ledger_time + delay;
-    e.storage()
-        .persistent()
-        .set(&DataKey::Scheduler(operation_id.clone()), &time);
+    operation_id
 }
 
-fn _check_execute(e: &Env, operation_id: &BytesN<32>, predecessor: &Option<BytesN<32>>) {
-    if _get_operation_state(e, operation_id) != OperationState::Ready {
-        panic_with_error!(e, TimeLockError::TimeNotReady);
-    }
+/*
+ * Same as `propose`, with a description that is stored with the operation
+ * once it is scheduled. The description is not part of the operation id.
+ */
+pub(crate) fn propose_with_description(
+    e: &Env,
+    proposer: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+    delay: u64,
+    description: &String,
+) -> BytesN<32> {
+    let predecessors = _predecessors(e, predecessor);
+    let operation_id = _hash_call(e, call, salt, &predecessors, &Vec::new(e));
+    let proposal = _proposal(
+        e,
+        proposer,
+        &Vec::from_array(e, [call.clone()]),
+        &predecessors,
+        delay,
+        description,
+        &Vec::new(e),
+    );
+    _propose_calls(e, &operation_id, &proposal);
 
-    if let Some(predecessor) = predecessor {
-        if _get_operation_state(e, predecessor) != OperationState::Executed {
-            panic_with_error!(e, TimeLockError::PredecessorNotDone);
-        }
-    }
+    operation_id
 }
 
-fn _exec_external(e: &Env, target: &Address, fn_name: &Symbol, data: &Vec<Val>) {
-    let result = e.try_invoke_contract::<(), InvokeError>(&target, &fn_name, data.clone());
+/*
+ * Same as `propose`, for an operation that must be executed with
+ * `execute_with_auth` and the same `auth_entries`. The entries are part of
+ * the operation id, as for `schedule_with_auth`.
+ */
+pub(crate) fn propose_with_auth(
+    e: &Env,
+    proposer: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+    delay: u64,
+    auth_entries: &Vec<InvokerContractAuthEntry>,
+) -> BytesN<32> {
+    let predecessors = _predecessors(e, predecessor);
+    let operation_id = _hash_call(e, call, salt, &predecessors, auth_entries);
+    let proposal = _proposal(
+        e,
+        proposer,
+        &Vec::from_array(e, [call.clone()]),
+        &predecessors,
+        delay,
+        &String::from_str(e, ""),
+        auth_entries,
+    );
+    _propose_calls(e, &operation_id, &proposal);
 
-    match result {
-        Ok(_) => {}
-        Err(_) => {
-            panic_with_error!(e, TimeLockError::ExecuteFailed);
-        }
-    }
+    operation_id
 }
 
-fn _exec_native(e: &Env, fn_name: &Symbol, data: &Vec<Val>) {
-    let fn_name = fn_name.clone();
-    if fn_name == Symbol::new(e, "update_min_delay") {
-        _update_min_delay(e, data);
-    } else if fn_name == Symbol::new(e, "grant_role") {
-        _update_role(e, data, true);
-    } else if fn_name == Symbol::new(e, "revoke_role") {
-        _update_role(e, data, false);
-    } else if fn_name == Symbol::new(e, "update_owner") {
-        _update_owner(e, data);
-    } else {
-        panic_with_error!(e, TimeLockError::InvalidFuncName);
-    }
+pub(crate) fn propose_batch(
+    e: &Env,
+    proposer: &Address,
+    calls: &Vec<Call>,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+    delay: u64,
+) -> BytesN<32> {
+    propose_batch_with_predecessors(e, proposer, calls, salt, &_predecessors(e, predecessor), delay)
 }
 
-fn _hash_call(
+pub(crate) fn propose_batch_with_predecessors(
+    e: &Env,
+    proposer: &Address,
+    calls: &Vec<Call>,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+    delay: u64,
+) -> BytesN<32> {
+    let operation_id = _hash_call_batch(e, calls, salt, predecessors);
+    let proposal = _proposal(
+        e,
+        proposer,
+        calls,
+        predecessors,
+        delay,
+        &String::from_str(e, ""),
+        &Vec::new(e),
+    );
+    _propose_calls(e, &operation_id, &proposal);
+
+    operation_id
+}
+
+/*
+ * Same as `propose_with_description`, for a batch of calls.
+ */
+pub(crate) fn propose_batch_with_description(
     e: &Env,
-    target: &Address,
-    fn_name: &Symbol,
-    data: &Vec<Val>,
+    proposer: &Address,
+    calls: &Vec<Call>,
     salt: &BytesN<32>,
     predecessor: &Option<BytesN<32>>,
+    delay: u64,
+    description: &String,
 ) -> BytesN<32> {
-    let mut calldata = Bytes::new(e);
-    calldata.append(&target.clone().to_xdr(e));
-    calldata.append(&fn_name.clone().to_xdr(e));
-    calldata.append(&data.clone().to_xdr(e));
-    if let Some(predecessor) = predecessor {
-        calldata.append(&predecessor.clone().to_xdr(e));
+    let predecessors = _predecessors(e, predecessor);
+    let operation_id = _hash_call_batch(e, calls, salt, &predecessors);
+    let proposal = _proposal(
+        e,
+        proposer,
+        calls,
+        &predecessors,
+        delay,
+        description,
+        &Vec::new(e),
+    );
+    _propose_calls(e, &operation_id, &proposal);
+
+    operation_id
+}
+
+/*
+ * Adds the approval of `approver` to a proposal. The operation is scheduled,
+ * and its delay starts counting, once the proposal threshold is reached.
+ */
+pub(crate) fn approve(e: &Env, approver: &Address, operation_id: &BytesN<32>) {
+    let mut proposal = match get_proposal(e, operation_id) {
+        Some(proposal) => proposal,
+        None => panic_with_error!(e, TimeLockError::InvalidStatus),
+    };
+
+    if proposal.approvals.contains(approver) {
+        panic_with_error!(e, TimeLockError::AlreadyApproved);
     }
-    calldata.append(&salt.clone().to_xdr(e));
-    e.crypto().sha256(&calldata)
+
+    proposal.approvals.push_back(approver.clone());
+    _record_approval(e, operation_id, approver, &proposal);
 }
 
-fn _is_contract(env: &Env, address: &Address) -> bool {
-    let address_ = address.to_string().to_xdr(env);
-    let first_char_index = address_.get(8).unwrap();
-    if first_char_index == 67_u8 {
-        return true;
+pub(crate) fn revoke_approval(e: &Env, approver: &Address, operation_id: &BytesN<32>) {
+    let mut proposal = match get_proposal(e, operation_id) {
+        Some(proposal) => proposal,
+        None => panic_with_error!(e, TimeLockError::InvalidStatus),
+    };
+
+    match proposal.approvals.first_index_of(approver) {
+        Some(index) => proposal.approvals.remove(index),
+        None => panic_with_error!(e, TimeLockError::NotApproved),
+    };
+
+    let key = DataKey::Proposal(operation_id.clone());
+    if proposal.approvals.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &proposal);
     }
-    false
+
+    e.events().publish(
+        (Symbol::new(e, "ApprovalRevoked"), operation_id.clone()),
+        (approver.clone(), _approval_count(e, &proposal)),
+    );
 }
 
-fn _update_min_delay(e: &Env, data: &Vec<Val>) {
-    let delay = data.get(0);
-    if let Some(delay) = delay {
-        let p = u64::try_from_val(e, &delay);
-        if let Ok(delay) = p {
-            update_min_delay(e, delay);
+pub(crate) fn get_proposal(e: &Env, operation_id: &BytesN<32>) -> Option<Proposal> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Proposal(operation_id.clone()))
+}
+
+pub(crate) fn update_proposal_threshold(e: &Env, threshold: u32) {
+    if threshold == 0 {
+        panic_with_error!(e, TimeLockError::InvalidParams);
+    }
+
+    e.storage()
+        .instance()
+        .set(&DataKey::ProposalThreshold, &threshold);
+    e.events()
+        .publish((Symbol::new(e, "ProposalThresholdUpdated"),), threshold);
+}
+
+/*
+ * Returns the number of distinct proposers that must approve an operation
+ * before it is scheduled. 1 means a single proposer can schedule directly.
+ */
+pub(crate) fn get_proposal_threshold(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::ProposalThreshold)
+        .unwrap_or(1)
+}
+
+pub(crate) fn cancel(e: &Env, canceller: &Address, operation_id: &BytesN<32>) {
+    let state = get_operation_state(e, operation_id);
+    if state == OperationState::Ready
+        || state == OperationState::Waiting
+        || state == OperationState::Expired
+    {
+        let mut operation = get_operation(e, operation_id).unwrap();
+        operation.cancelled_at = e.ledger().timestamp();
+        operation.status = OperationStatus::Cancelled;
+        e.storage()
+            .persistent()
+            .set(&DataKey::Scheduler(operation_id.clone()), &operation);
+        _remove_pending(e, operation_id);
+    } else {
+        panic_with_error!(e, TimeLockError::InvalidStatus);
+    }
+
+    e.events().publish(
+        (Symbol::new(e, "OperationCancelled"),),
+        CancelledEvent {
+            opt_id: operation_id.clone(),
+            canceller: canceller.clone(),
+        },
+    );
+}
+
+pub(crate) fn cancel_batch(e: &Env, canceller: &Address, operation_ids: &Vec<BytesN<32>>) {
+    if operation_ids.is_empty() {
+        panic_with_error!(e, TimeLockError::InvalidParams);
+    }
+
+    for operation_id in operation_ids.iter() {
+        cancel(e, canceller, &operation_id);
+    }
+}
+
+/*
+ * Drops the record of a cancelled operation, so that an operation with the
+ * same id can be scheduled again.
+ */
+pub(crate) fn clear_cancellation(e: &Env, operation_id: &BytesN<32>) {
+    match get_operation(e, operation_id) {
+        Some(operation) if operation.status == OperationStatus::Cancelled => {
+            e.storage()
+                .persistent()
+                .remove(&DataKey::Scheduler(operation_id.clone()));
+        }
+        _ => panic_with_error!(e, TimeLockError::InvalidStatus),
+    }
+
+    e.events().publish(
+        (Symbol::new(e, "CancellationCleared"),),
+        operation_id.clone(),
+    );
+}
+
+pub(crate) fn update_min_delay(e: &Env, delay: u64) {
+    if delay > MAX_MIN_DELAY {
+        panic_with_error!(e, TimeLockError::DelayTooLong);
+    }
+
+    let old: u64 = e.storage().instance().get(&DataKey::MinDelay).unwrap_or(0);
+    e.storage().instance().set(&DataKey::MinDelay, &delay);
+    e.events().publish(
+        (Symbol::new(e, "MinDelayUpdated"),),
+        MinDelayChangedEvent { old, new: delay },
+    );
+}
+
+/*
+ * Sets the minimum delay of every operation calling `target`. 0 removes the override.
+ */
+pub(crate) fn update_target_min_delay(e: &Env, target: &Address, delay: u64) {
+    if delay > MAX_MIN_DELAY {
+        panic_with_error!(e, TimeLockError::DelayTooLong);
+    }
+
+    _set_min_delay_override(e, &DataKey::TargetMinDelay(target.clone()), delay);
+    e.events().publish(
+        (Symbol::new(e, "TargetMinDelayUpdated"), target.clone()),
+        delay,
+    );
+}
+
+/*
+ * Sets the minimum delay of every operation calling `fn_name` on `target`. 0 removes the override.
+ */
+pub(crate) fn update_fn_min_delay(e: &Env, target: &Address, fn_name: &Symbol, delay: u64) {
+    if delay > MAX_MIN_DELAY {
+        panic_with_error!(e, TimeLockError::DelayTooLong);
+    }
+
+    _set_min_delay_override(
+        e,
+        &DataKey::FnMinDelay(target.clone(), fn_name.clone()),
+        delay,
+    );
+    e.events().publish(
+        (
+            Symbol::new(e, "FnMinDelayUpdated"),
+            target.clone(),
+            fn_name.clone(),
+        ),
+        delay,
+    );
+}
+
+/*
+ * Returns the minimum delay applying to a call: the strictest of the global
+ * minimum delay, the target's override and the function's override.
+ * The overrides that are read are kept alive.
+ */
+pub(crate) fn get_min_delay_for(e: &Env, target: &Address, fn_name: &Symbol) -> u64 {
+    let min_delay: u64 = e.storage().instance().get(&DataKey::MinDelay).unwrap_or(0);
+    let target_delay = _get_min_delay_override(e, &DataKey::TargetMinDelay(target.clone()));
+    let fn_delay =
+        _get_min_delay_override(e, &DataKey::FnMinDelay(target.clone(), fn_name.clone()));
+    min_delay.max(target_delay).max(fn_delay)
+}
+
+pub(crate) fn update_grace_period(e: &Env, grace_period: u64) {
+    if grace_period > MAX_GRACE_PERIOD {
+        panic_with_error!(e, TimeLockError::GracePeriodTooLong);
+    }
+
+    e.storage()
+        .instance()
+        .set(&DataKey::GracePeriod, &grace_period);
+    e.events()
+        .publish((Symbol::new(e, "GracePeriodUpdated"),), grace_period);
+}
+
+/*
+ * Returns how long, in seconds, an operation stays executable once it is
+ * ready. 0 means operations never expire.
+ */
+pub(crate) fn get_grace_period(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&DataKey::GracePeriod)
+        .unwrap_or(0)
+}
+
+pub(crate) fn update_open_execution(e: &Env, open: bool) {
+    e.storage().instance().set(&DataKey::OpenExecution, &open);
+    e.events()
+        .publish((Symbol::new(e, "OpenExecutionUpdated"),), open);
+}
+
+/*
+ * Returns `true` if anyone may execute ready operations, without holding
+ * the executor role.
+ */
+pub(crate) fn is_open_execution(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::OpenExecution)
+        .unwrap_or(false)
+}
+
+pub(crate) fn update_ttl_margin(e: &Env, margin: u32) {
+    e.storage().instance().set(&DataKey::TtlMargin, &margin);
+    e.events()
+        .publish((Symbol::new(e, "TtlMarginUpdated"),), margin);
+}
+
+pub(crate) fn get_ttl_margin(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::TtlMargin)
+        .unwrap_or(DEFAULT_TTL_MARGIN)
+}
+
+/*
+ * Stops scheduling and execution, and freezes the countdown of scheduled
+ * operations, until `unpause` is called.
+ */
+pub(crate) fn pause(e: &Env, guardian: &Address) {
+    if is_paused(e) {
+        panic_with_error!(e, TimeLockError::Paused);
+    }
+
+    e.storage()
+        .instance()
+        .set(&DataKey::PausedAt, &e.ledger().timestamp());
+    e.events()
+        .publish((Symbol::new(e, "Paused"),), guardian.clone());
+}
+
+pub(crate) fn unpause(e: &Env) {
+    let paused_at: u64 = match e.storage().instance().get(&DataKey::PausedAt) {
+        Some(paused_at) => paused_at,
+        None => panic_with_error!(e, TimeLockError::NotPaused),
+    };
+
+    let duration = e.ledger().timestamp() - paused_at;
+    let paused_duration: u64 = e
+        .storage()
+        .instance()
+        .get(&DataKey::PausedDuration)
+        .unwrap_or(0);
+    e.storage()
+        .instance()
+        .set(&DataKey::PausedDuration, &(paused_duration + duration));
+    e.storage().instance().remove(&DataKey::PausedAt);
+    e.events()
+        .publish((Symbol::new(e, "Unpaused"),), duration);
+}
+
+pub(crate) fn is_paused(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::PausedAt)
+}
+
+/*
+ * Replaces the code of the timelock. The new code takes effect once the
+ * current invocation returns, after which `migrate` brings the storage up
+ * to date with it.
+ */
+pub(crate) fn upgrade(e: &Env, new_wasm_hash: &BytesN<32>) {
+    e.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+    e.events()
+        .publish((Symbol::new(e, "Upgraded"),), new_wasm_hash.clone());
+}
+
+/*
+ * Runs the storage migrations needed by the current code, then records
+ * `VERSION` as the storage version. `initialize` records the version it was
+ * deployed with, and version 1 is the first one, so there is nothing to
+ * migrate yet: the call fails until a later version adds migrations.
+ */
+pub(crate) fn migrate(e: &Env) {
+    let version = get_storage_version(e);
+    if version >= VERSION {
+        panic_with_error!(e, TimeLockError::InvalidStatus);
+    }
+
+    e.storage().instance().set(&DataKey::Version, &VERSION);
+    e.events()
+        .publish((Symbol::new(e, "Migrated"),), (version, VERSION));
+}
+
+/*
+ * Returns the version recorded by `initialize` or by the last `migrate`, 0
+ * before initialization.
+ */
+pub(crate) fn get_storage_version(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Version).unwrap_or(0)
+}
+
+pub(crate) fn extend_instance_ttl(e: &Env) {
+    let extend_to = clamp_ttl(e, TTL_EXTEND_TO);
+    e.storage()
+        .instance()
+        .extend_ttl(TTL_THRESHOLD.min(extend_to), extend_to);
+}
+
+/*
+ * Extends the TTL of an operation so that it stays alive until it is ready,
+ * plus the TTL margin.
+ */
+pub(crate) fn bump_operation(e: &Env, operation_id: &BytesN<32>) {
+    if let Some(operation) = get_operation(e, operation_id) {
+        let remaining = if operation.status == OperationStatus::Pending {
+            _lock_time(e, &operation)
+                .saturating_add(operation.grace_period)
+                .saturating_sub(e.ledger().timestamp())
+        } else {
+            0
+        };
+        _extend_operation_ttl(e, operation_id, remaining);
+    } else {
+        panic_with_error!(e, TimeLockError::InvalidStatus);
+    }
+}
+
+/*
+ * Caps a TTL, in ledgers, to the maximum allowed by the network.
+ */
+pub(crate) fn clamp_ttl(e: &Env, ledgers: u32) -> u32 {
+    ledgers.min(e.storage().max_ttl() - 1)
+}
+
+pub(crate) fn get_schedule_lock_time(e: &Env, operation_id: &BytesN<32>) -> u64 {
+    match get_operation(e, operation_id) {
+        Some(operation) => match operation.status {
+            OperationStatus::Pending => _lock_time(e, &operation),
+            OperationStatus::Executed => DONE_TIMESTAMP,
+            OperationStatus::Cancelled => 0_u64,
+        },
+        None => 0_u64,
+    }
+}
+
+pub(crate) fn get_operation(e: &Env, operation_id: &BytesN<32>) -> Option<Operation> {
+    let key = DataKey::Scheduler(operation_id.clone());
+    e.storage().persistent().get::<DataKey, Operation>(&key)
+}
+
+/*
+ * Number of indexes handed out to scheduled operations so far, the upper
+ * bound of the `start` and `limit` windows of the pending views.
+ */
+pub(crate) fn get_pending_index_len(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::PendingLen).unwrap_or(0)
+}
+
+/*
+ * Returns the pending operations, waiting or ready, among the `limit` indexes
+ * from `start`, in the order they were scheduled. Indexes of executed,
+ * cancelled or pruned operations are skipped and expired operations are left
+ * out, so a window may hold fewer than `limit` operations.
+ */
+pub(crate) fn get_pending_operations(e: &Env, start: u32, limit: u32) -> Vec<PendingOperation> {
+    let mut operations = Vec::new(e);
+    for index in _pending_range(e, start, limit) {
+        if let Some(operation_id) = _pending_id(e, index) {
+            if is_operation_pending(e, &operation_id) {
+                operations.push_back(_pending_operation(e, &operation_id));
+            }
+        }
+    }
+    operations
+}
+
+/*
+ * Returns `true` if an operation is scheduled and neither executed, cancelled
+ * nor expired: its state is `Waiting` or `Ready`.
+ */
+pub(crate) fn is_operation_pending(e: &Env, operation_id: &BytesN<32>) -> bool {
+    let state = get_operation_state(e, operation_id);
+    state == OperationState::Waiting || state == OperationState::Ready
+}
+
+/*
+ * Same as `get_pending_operations`, only keeping the operations that can be
+ * executed now.
+ */
+pub(crate) fn get_ready_operations(e: &Env, start: u32, limit: u32) -> Vec<PendingOperation> {
+    let mut operations = Vec::new(e);
+    for index in _pending_range(e, start, limit) {
+        if let Some(operation_id) = _pending_id(e, index) {
+            if get_operation_state(e, &operation_id) == OperationState::Ready {
+                operations.push_back(_pending_operation(e, &operation_id));
+            }
+        }
+    }
+    operations
+}
+
+/*
+ * Removes the expired operations among the `limit` indexes from `start` from
+ * the pending index and returns how many were removed. Their records are kept,
+ * so they can still be cancelled.
+ */
+pub(crate) fn prune_expired_operations(e: &Env, start: u32, limit: u32) -> u32 {
+    let mut pruned = 0;
+    for index in _pending_range(e, start, limit) {
+        if let Some(operation_id) = _pending_id(e, index) {
+            if get_operation_state(e, &operation_id) == OperationState::Expired {
+                _remove_pending(e, &operation_id);
+                pruned += 1;
+            }
+        }
+    }
+    pruned
+}
+
+/*
+ * Returns the id of the operation containing a single call with these
+ * parameters, as returned by `schedule`.
+ */
+pub(crate) fn hash_operation(
+    e: &Env,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+) -> BytesN<32> {
+    _hash_call(e, call, salt, &_predecessors(e, predecessor), &Vec::new(e))
+}
+
+/*
+ * Same as `hash_operation`, for an operation scheduled with
+ * `schedule_with_predecessors`.
+ */
+pub(crate) fn hash_operation_with_predecessors(
+    e: &Env,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+) -> BytesN<32> {
+    _hash_call(e, call, salt, predecessors, &Vec::new(e))
+}
+
+/*
+ * Same as `hash_operation`, for an operation scheduled with `schedule_with_auth`.
+ */
+pub(crate) fn hash_operation_with_auth(
+    e: &Env,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+    auth_entries: &Vec<InvokerContractAuthEntry>,
+) -> BytesN<32> {
+    _hash_call(e, call, salt, &_predecessors(e, predecessor), auth_entries)
+}
+
+/*
+ * Returns the id of the batch operation with these parameters, as returned
+ * by `schedule_batch`.
+ */
+pub(crate) fn hash_operation_batch(
+    e: &Env,
+    calls: &Vec<Call>,
+    salt: &BytesN<32>,
+    predecessor: &Option<BytesN<32>>,
+) -> BytesN<32> {
+    _hash_call_batch(e, calls, salt, &_predecessors(e, predecessor))
+}
+
+/*
+ * Same as `hash_operation_batch`, for an operation scheduled with
+ * `schedule_batch_with_predecessors`.
+ */
+pub(crate) fn hash_operation_batch_with_predecessors(
+    e: &Env,
+    calls: &Vec<Call>,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+) -> BytesN<32> {
+    _hash_call_batch(e, calls, salt, predecessors)
+}
+
+/*
+ * Pairs up the parameters of a batch into calls. `targets`, `fn_names` and
+ * `datas` must be non-empty and of the same length.
+ */
+pub(crate) fn batch_calls(
+    e: &Env,
+    targets: &Vec<Address>,
+    fn_names: &Vec<Symbol>,
+    datas: &Vec<Vec<Val>>,
+) -> Vec<Call> {
+    if targets.is_empty() || targets.len() != fn_names.len() || targets.len() != datas.len() {
+        panic_with_error!(e, TimeLockError::InvalidParams);
+    }
+
+    let mut calls = Vec::new(e);
+    for index in 0..targets.len() {
+        calls.push_back(Call {
+            target: targets.get_unchecked(index),
+            fn_name: fn_names.get_unchecked(index),
+            data: datas.get_unchecked(index),
+        });
+    }
+    calls
+}
+
+pub(crate) fn get_operation_state(e: &Env, operation_id: &BytesN<32>) -> OperationState {
+    let operation = match get_operation(e, operation_id) {
+        Some(operation) => operation,
+        None => return OperationState::Unset,
+    };
+    let ledger_time = e.ledger().timestamp();
+    match operation.status {
+        OperationStatus::Cancelled => OperationState::Unset,
+        OperationStatus::Executed => OperationState::Executed,
+        OperationStatus::Pending => {
+            let lock_time = _lock_time(e, &operation);
+            if ledger_time < lock_time {
+                OperationState::Waiting
+            } else if _is_expired(&operation, lock_time, ledger_time) {
+                OperationState::Expired
+            } else {
+                OperationState::Ready
+            }
+        }
+    }
+}
+
+/*
+ * Returns the time at which a pending operation becomes ready, pushed back
+ * by the time the timelock has been paused since it was scheduled.
+ * Operations that only unpause the timelock are not held back by pauses.
+ */
+fn _lock_time(e: &Env, operation: &Operation) -> u64 {
+    if _is_unpause(e, &operation.targets, &operation.fn_names) {
+        operation.ready_at
+    } else {
+        operation.ready_at + (_paused_time(e) - operation.paused_offset)
+    }
+}
+
+/*
+ * Returns the total time the timelock has been paused, including the
+ * current pause.
+ */
+fn _paused_time(e: &Env) -> u64 {
+    let paused_duration: u64 = e
+        .storage()
+        .instance()
+        .get(&DataKey::PausedDuration)
+        .unwrap_or(0);
+    match e.storage().instance().get::<DataKey, u64>(&DataKey::PausedAt) {
+        Some(paused_at) => paused_duration + (e.ledger().timestamp() - paused_at),
+        None => paused_duration,
+    }
+}
+
+fn _check_not_paused(e: &Env, calls: &Vec<Call>) {
+    if is_paused(e) && !_is_unpause(e, &_targets(e, calls), &_fn_names(e, calls)) {
+        panic_with_error!(e, TimeLockError::Paused);
+    }
+}
+
+fn _is_unpause(e: &Env, targets: &Vec<Address>, fn_names: &Vec<Symbol>) -> bool {
+    let unpause = Symbol::new(e, "unpause");
+    targets
+        .iter()
+        .zip(fn_names.iter())
+        .all(|(target, fn_name)| target == e.current_contract_address() && fn_name == unpause)
+}
+
+fn _get_min_delay_override(e: &Env, key: &DataKey) -> u64 {
+    match e.storage().persistent().get::<DataKey, u64>(key) {
+        Some(delay) => {
+            _extend_ttl(e, key);
+            delay
+        }
+        None => 0,
+    }
+}
+
+fn _set_min_delay_override(e: &Env, key: &DataKey, delay: u64) {
+    if delay == 0 {
+        e.storage().persistent().remove(key);
+    } else {
+        e.storage().persistent().set(key, &delay);
+        _extend_ttl(e, key);
+    }
+}
+
+fn _extend_ttl(e: &Env, key: &DataKey) {
+    let extend_to = clamp_ttl(e, TTL_EXTEND_TO);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD.min(extend_to), extend_to);
+}
+
+fn _is_expired(operation: &Operation, lock_time: u64, ledger_time: u64) -> bool {
+    operation.grace_period != 0 && ledger_time >= lock_time.saturating_add(operation.grace_period)
+}
+
+fn _add_operation(
+    e: &Env,
+    operation_id: &BytesN<32>,
+    proposer: &Address,
+    calls: &Vec<Call>,
+    predecessors: &Vec<BytesN<32>>,
+    delay: u64,
+    description: &String,
+) {
+    let ledger_time = e.ledger().timestamp();
+    _check_not_cancelled(e, operation_id);
+    if get_operation_state(e, operation_id) != OperationState::Unset {
+        panic_with_error!(e, TimeLockError::AlreadyExists);
+    }
+
+    let operation = Operation {
+        proposer: proposer.clone(),
+        targets: _targets(e, calls),
+        fn_names: _fn_names(e, calls),
+        args_hash: e.crypto().sha256(&_datas(e, calls).to_xdr(e)),
+        predecessors: predecessors.clone(),
+        scheduled_at: ledger_time,
+        ready_at: ledger_time + delay,
+        paused_offset: _paused_time(e),
+        grace_period: get_grace_period(e),
+        executed_at: 0,
+        cancelled_at: 0,
+        executor: OperationExecutor::None,
+        status: OperationStatus::Pending,
+        last_failure: ExecutionFailure::None,
+        description: description.clone(),
+    };
+    e.storage()
+        .persistent()
+        .set(&DataKey::Scheduler(operation_id.clone()), &operation);
+    _add_pending(e, operation_id);
+    _extend_operation_ttl(e, operation_id, delay.saturating_add(operation.grace_period));
+}
+
+/*
+ * The pending index is append only: each operation gets the next index, and
+ * only its own entries are removed once it is executed, cancelled or pruned.
+ * Operations therefore never touch each other's entries, which share the TTL
+ * of the operation they point to.
+ */
+fn _add_pending(e: &Env, operation_id: &BytesN<32>) {
+    let index = get_pending_index_len(e);
+    e.storage().persistent().set(&DataKey::PendingAt(index), operation_id);
+    e.storage()
+        .persistent()
+        .set(&DataKey::PendingIndex(operation_id.clone()), &index);
+    e.storage().instance().set(&DataKey::PendingLen, &(index + 1));
+}
+
+fn _remove_pending(e: &Env, operation_id: &BytesN<32>) {
+    let key = DataKey::PendingIndex(operation_id.clone());
+    if let Some(index) = e.storage().persistent().get::<DataKey, u32>(&key) {
+        e.storage().persistent().remove(&DataKey::PendingAt(index));
+        e.storage().persistent().remove(&key);
+    }
+}
+
+fn _pending_id(e: &Env, index: u32) -> Option<BytesN<32>> {
+    e.storage().persistent().get(&DataKey::PendingAt(index))
+}
+
+fn _pending_range(e: &Env, start: u32, limit: u32) -> core::ops::Range<u32> {
+    let len = get_pending_index_len(e);
+    start.min(len)..start.saturating_add(limit).min(len)
+}
+
+fn _pending_operation(e: &Env, operation_id: &BytesN<32>) -> PendingOperation {
+    PendingOperation {
+        opt_id: operation_id.clone(),
+        ready_at: get_schedule_lock_time(e, operation_id),
+    }
+}
+
+/*
+ * A cancelled operation is kept until its record is explicitly cleared with
+ * `clear_cancellation`, so that it cannot be scheduled again unnoticed.
+ */
+fn _check_not_cancelled(e: &Env, operation_id: &BytesN<32>) {
+    if let Some(operation) = get_operation(e, operation_id) {
+        if operation.status == OperationStatus::Cancelled {
+            panic_with_error!(e, TimeLockError::OperationCancelled);
+        }
+    }
+}
+
+fn _extend_operation_ttl(e: &Env, operation_id: &BytesN<32>, seconds: u64) {
+    let ledgers = (seconds / LEDGER_SECONDS).min(u32::MAX as u64) as u32;
+    let extend_to = clamp_ttl(e, ledgers.saturating_add(get_ttl_margin(e)));
+    let storage = e.storage().persistent();
+    storage.extend_ttl(
+        &DataKey::Scheduler(operation_id.clone()),
+        extend_to,
+        extend_to,
+    );
+
+    let index_key = DataKey::PendingIndex(operation_id.clone());
+    if let Some(index) = storage.get::<DataKey, u32>(&index_key) {
+        storage.extend_ttl(&index_key, extend_to, extend_to);
+        storage.extend_ttl(&DataKey::PendingAt(index), extend_to, extend_to);
+    }
+}
+
+fn _check_execute(e: &Env, operation_id: &BytesN<32>, predecessors: &Vec<BytesN<32>>) {
+    match get_operation_state(e, operation_id) {
+        OperationState::Ready => {}
+        OperationState::Expired => panic_with_error!(e, TimeLockError::OperationExpired),
+        _ => panic_with_error!(e, TimeLockError::TimeNotReady),
+    }
+
+    for predecessor in predecessors.iter() {
+        if get_operation_state(e, &predecessor) != OperationState::Executed {
+            panic_with_error!(e, TimeLockError::PredecessorNotDone);
+        }
+    }
+}
+
+fn _check_no_approval_required(e: &Env) {
+    if get_proposal_threshold(e) > 1 {
+        panic_with_error!(e, TimeLockError::ApprovalRequired);
+    }
+}
+
+fn _schedule_batch(
+    e: &Env,
+    proposer: &Address,
+    calls: &Vec<Call>,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+    delay: u64,
+    description: &String,
+) -> BytesN<32> {
+    _check_no_approval_required(e);
+
+    let operation_id = _hash_call_batch(e, calls, salt, predecessors);
+    _schedule_calls(
+        e,
+        &operation_id,
+        proposer,
+        calls,
+        predecessors,
+        delay,
+        description,
+    );
+
+    operation_id
+}
+
+fn _schedule_call(
+    e: &Env,
+    operation_id: &BytesN<32>,
+    proposer: &Address,
+    call: &Call,
+    predecessors: &Vec<BytesN<32>>,
+    delay: u64,
+    description: &String,
+) -> BytesN<32> {
+    _check_no_approval_required(e);
+
+    _schedule_calls(
+        e,
+        operation_id,
+        proposer,
+        &Vec::from_array(e, [call.clone()]),
+        predecessors,
+        delay,
+        description,
+    );
+
+    operation_id.clone()
+}
+
+fn _execute_call(
+    e: &Env,
+    executor: &Address,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+    auth_entries: &Vec<InvokerContractAuthEntry>,
+) -> Val {
+    let operation_id = _hash_call(e, call, salt, predecessors, auth_entries);
+    _execute_calls(
+        e,
+        &operation_id,
+        executor,
+        &Vec::from_array(e, [call.clone()]),
+        predecessors,
+        auth_entries,
+    )
+    .get_unchecked(0)
+}
+
+/*
+ * New proposal, approved by its proposer.
+ */
+fn _proposal(
+    e: &Env,
+    proposer: &Address,
+    calls: &Vec<Call>,
+    predecessors: &Vec<BytesN<32>>,
+    delay: u64,
+    description: &String,
+    auth_entries: &Vec<InvokerContractAuthEntry>,
+) -> Proposal {
+    Proposal {
+        proposer: proposer.clone(),
+        calls: calls.clone(),
+        predecessors: predecessors.clone(),
+        delay,
+        description: description.clone(),
+        auth_entries: auth_entries.clone(),
+        approvals: Vec::from_array(e, [proposer.clone()]),
+    }
+}
+
+fn _propose_calls(e: &Env, operation_id: &BytesN<32>, proposal: &Proposal) {
+    _check_not_cancelled(e, operation_id);
+    if get_operation_state(e, operation_id) != OperationState::Unset
+        || get_proposal(e, operation_id).is_some()
+    {
+        panic_with_error!(e, TimeLockError::AlreadyExists);
+    }
+
+    _check_not_paused(e, &proposal.calls);
+    _check_calls(e, &proposal.calls, proposal.delay);
+
+    _record_approval(e, operation_id, &proposal.proposer, proposal);
+}
+
+/*
+ * Stores a proposal after an approval was added, or schedules it and drops
+ * the proposal once the threshold is reached.
+ */
+fn _record_approval(e: &Env, operation_id: &BytesN<32>, approver: &Address, proposal: &Proposal) {
+    let key = DataKey::Proposal(operation_id.clone());
+    let approval_count = _approval_count(e, proposal);
+    e.events().publish(
+        (Symbol::new(e, "OperationApproved"), operation_id.clone()),
+        (approver.clone(), approval_count),
+    );
+
+    if approval_count >= get_proposal_threshold(e) {
+        e.storage().persistent().remove(&key);
+        _schedule_calls(
+            e,
+            operation_id,
+            &proposal.proposer,
+            &proposal.calls,
+            &proposal.predecessors,
+            proposal.delay,
+            &proposal.description,
+        );
+    } else {
+        let extend_to = clamp_ttl(e, TTL_EXTEND_TO);
+        e.storage().persistent().set(&key, proposal);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD.min(extend_to), extend_to);
+    }
+}
+
+/*
+ * Number of approvals given by accounts that still hold the proposer role.
+ */
+fn _approval_count(e: &Env, proposal: &Proposal) -> u32 {
+    let proposer_role = RoleLabel::Proposer.to_symbol();
+    proposal
+        .approvals
+        .iter()
+        .filter(|approver| role_base::has_role(e, approver, &proposer_role))
+        .count() as u32
+}
+
+fn _check_calls(e: &Env, calls: &Vec<Call>, delay: u64) {
+    for call in calls.iter() {
+        if !_is_contract(e, &call.target) {
+            panic_with_error!(e, TimeLockError::InvalidParams);
+        }
+    }
+
+    for call in calls.iter() {
+        if delay < get_min_delay_for(e, &call.target, &call.fn_name) {
+            panic_with_error!(e, TimeLockError::InsufficientDelay);
+        }
+    }
+}
+
+fn _schedule_calls(
+    e: &Env,
+    operation_id: &BytesN<32>,
+    proposer: &Address,
+    calls: &Vec<Call>,
+    predecessors: &Vec<BytesN<32>>,
+    delay: u64,
+    description: &String,
+) {
+    _check_not_paused(e, calls);
+    _check_calls(e, calls, delay);
+
+    _add_operation(
+        e,
+        operation_id,
+        proposer,
+        calls,
+        predecessors,
+        delay,
+        description,
+    );
+
+    for (index, call) in calls.iter().enumerate() {
+        e.events().publish(
+            (Symbol::new(e, "CallScheduled"),),
+            CallScheduledEvent {
+                opt_id: operation_id.clone(),
+                index: index as u32,
+                target: call.target,
+                fn_name: call.fn_name,
+                data: call.data,
+                predecessors: predecessors.clone(),
+                delay,
+                description: description.clone(),
+            },
+        );
+    }
+}
+
+fn _execute_calls(
+    e: &Env,
+    operation_id: &BytesN<32>,
+    executor: &Address,
+    calls: &Vec<Call>,
+    predecessors: &Vec<BytesN<32>>,
+    auth_entries: &Vec<InvokerContractAuthEntry>,
+) -> Vec<Val> {
+    _check_not_paused(e, calls);
+    _check_execute(e, operation_id, predecessors);
+
+    if !auth_entries.is_empty() {
+        e.authorize_as_current_contract(auth_entries.clone());
+    }
+
+    let mut results = Vec::new(e);
+    for (index, call) in calls.iter().enumerate() {
+        let result = if call.target == e.current_contract_address() {
+            _exec_native(e, &call.fn_name, &call.data)
+        } else {
+            _exec_external(e, &call)
+        };
+        results.push_back(result);
+
+        e.events().publish(
+            (Symbol::new(e, "CallExecuted"),),
+            CallExecutedEvent {
+                opt_id: operation_id.clone(),
+                index: index as u32,
+                target: call.target,
+                fn_name: call.fn_name,
+                data: call.data,
+                result: Vec::from_array(e, [result]),
+            },
+        );
+    }
+
+    _mark_executed(e, operation_id, executor);
+    results
+}
+
+fn _mark_executed(e: &Env, operation_id: &BytesN<32>, executor: &Address) {
+    let mut operation = get_operation(e, operation_id).unwrap();
+    operation.executed_at = e.ledger().timestamp();
+    operation.executor = Some(executor.clone()).into();
+    operation.status = OperationStatus::Executed;
+    e.storage()
+        .persistent()
+        .set(&DataKey::Scheduler(operation_id.clone()), &operation);
+    _remove_pending(e, operation_id);
+}
+
+fn _predecessors(e: &Env, predecessor: &Option<BytesN<32>>) -> Vec<BytesN<32>> {
+    match predecessor {
+        Some(predecessor) => Vec::from_array(e, [predecessor.clone()]),
+        None => Vec::new(e),
+    }
+}
+
+fn _targets(e: &Env, calls: &Vec<Call>) -> Vec<Address> {
+    let mut targets = Vec::new(e);
+    for call in calls.iter() {
+        targets.push_back(call.target);
+    }
+    targets
+}
+
+fn _fn_names(e: &Env, calls: &Vec<Call>) -> Vec<Symbol> {
+    let mut fn_names = Vec::new(e);
+    for call in calls.iter() {
+        fn_names.push_back(call.fn_name);
+    }
+    fn_names
+}
+
+fn _datas(e: &Env, calls: &Vec<Call>) -> Vec<Vec<Val>> {
+    let mut datas = Vec::new(e);
+    for call in calls.iter() {
+        datas.push_back(call.data);
+    }
+    datas
+}
+
+fn _exec_external(e: &Env, call: &Call) -> Val {
+    match _try_exec_external(e, call) {
+        Ok(result) => result,
+        Err(_) => panic_with_error!(e, TimeLockError::ExecuteFailed),
+    }
+}
+
+fn _try_exec_external(e: &Env, call: &Call) -> Result<Val, ExecutionFailure> {
+    let result = e.try_invoke_contract::<Val, Error>(&call.target, &call.fn_name, call.data.clone());
+
+    match result {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(_)) => panic_with_error!(e, TimeLockError::ExecuteFailed),
+        Err(Ok(error)) => {
+            if error.is_type(ScErrorType::Contract) {
+                Err(ExecutionFailure::Contract(error.get_code()))
+            } else {
+                let error_type = ScErrorType::VARIANTS
+                    .iter()
+                    .find(|error_type| error.is_type(**error_type))
+                    .map_or(0, |error_type| *error_type as u32);
+                Err(ExecutionFailure::Host(error_type, error.get_code()))
+            }
+        }
+        Err(Err(_)) => panic_with_error!(e, TimeLockError::ExecuteFailed),
+    }
+}
+
+/*
+ * Functions of the timelock itself that operations can call, with the
+ * handler decoding their arguments. Native functions return nothing, their
+ * result is `()`.
+ */
+type NativeFunction = fn(&Env, &Vec<Val>);
+
+const NATIVE_FUNCTIONS: [(&str, NativeFunction); 16] = [
+    ("update_min_delay", _update_min_delay),
+    ("grant_role", |e, data| _update_role(e, data, true)),
+    ("revoke_role", |e, data| _update_role(e, data, false)),
+    ("set_role_admin", _set_role_admin),
+    ("update_owner", _update_owner),
+    ("cancel_ownership_transfer", |e, _| owner::cancel_ownership_transfer(e)),
+    ("renounce_ownership", |e, _| owner::renounce_ownership(e)),
+    ("unpause", |e, _| unpause(e)),
+    ("upgrade", _upgrade),
+    ("migrate", |e, _| migrate(e)),
+    ("update_target_min_delay", _update_target_min_delay),
+    ("update_fn_min_delay", _update_fn_min_delay),
+    ("update_grace_period", _update_grace_period),
+    ("update_ttl_margin", _update_ttl_margin),
+    ("update_proposal_threshold", _update_proposal_threshold),
+    ("update_open_execution", _update_open_execution),
+];
+
+pub(crate) fn is_native_function(e: &Env, fn_name: &Symbol) -> bool {
+    _native_function(e, fn_name).is_some()
+}
+
+fn _native_function(e: &Env, fn_name: &Symbol) -> Option<NativeFunction> {
+    NATIVE_FUNCTIONS
+        .iter()
+        .find(|(name, _)| *fn_name == Symbol::new(e, name))
+        .map(|(_, handler)| *handler)
+}
+
+fn _exec_native(e: &Env, fn_name: &Symbol, data: &Vec<Val>) -> Val {
+    match _native_function(e, fn_name) {
+        Some(handler) => handler(e, data),
+        None => panic_with_error!(e, TimeLockError::InvalidFuncName),
+    }
+
+    ().into_val(e)
+}
+
+/*
+ * Prefix of every operation id: a versioned domain tag, the timelock address
+ * and the network id, so that an id is only valid on this instance.
+ */
+fn _operation_domain(e: &Env) -> Bytes {
+    let mut calldata = Bytes::from_slice(e, OPERATION_ID_DOMAIN);
+    calldata.append(&e.current_contract_address().to_xdr(e));
+    calldata.append(&e.ledger().network_id().to_xdr(e));
+    calldata
+}
+
+/*
+ * Encodes the predecessors the way a single `Option` predecessor was encoded:
+ * void when there is none and the id itself when there is one, so that those
+ * operation ids do not change. Longer lists are encoded as a vector.
+ */
+fn _predecessors_xdr(e: &Env, predecessors: &Vec<BytesN<32>>) -> Bytes {
+    if predecessors.len() > 1 {
+        predecessors.clone().to_xdr(e)
+    } else {
+        predecessors.first().to_xdr(e)
+    }
+}
+
+fn _hash_call(
+    e: &Env,
+    call: &Call,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+    auth_entries: &Vec<InvokerContractAuthEntry>,
+) -> BytesN<32> {
+    let mut calldata = _operation_domain(e);
+    calldata.append(&call.target.clone().to_xdr(e));
+    calldata.append(&call.fn_name.clone().to_xdr(e));
+    calldata.append(&call.data.clone().to_xdr(e));
+    calldata.append(&_predecessors_xdr(e, predecessors));
+    calldata.append(&salt.clone().to_xdr(e));
+    if !auth_entries.is_empty() {
+        calldata.append(&auth_entries.clone().to_xdr(e));
+    }
+    e.crypto().sha256(&calldata)
+}
+
+/*
+ * Batches are hashed as separate lists of targets, function names and data,
+ * as they were before being grouped into calls, so that their ids do not
+ * change.
+ */
+fn _hash_call_batch(
+    e: &Env,
+    calls: &Vec<Call>,
+    salt: &BytesN<32>,
+    predecessors: &Vec<BytesN<32>>,
+) -> BytesN<32> {
+    let mut calldata = _operation_domain(e);
+    calldata.append(&_targets(e, calls).to_xdr(e));
+    calldata.append(&_fn_names(e, calls).to_xdr(e));
+    calldata.append(&_datas(e, calls).to_xdr(e));
+    calldata.append(&_predecessors_xdr(e, predecessors));
+    calldata.append(&salt.clone().to_xdr(e));
+    e.crypto().sha256(&calldata)
+}
+
+fn _is_contract(env: &Env, address: &Address) -> bool {
+    let address_ = address.to_string().to_xdr(env);
+    let first_char_index = address_.get(8).unwrap();
+    if first_char_index == 67_u8 {
+        return true;
+    }
+    false
+}
+
+fn _update_min_delay(e: &Env, data: &Vec<Val>) {
+    let delay = data.get(0);
+    if let Some(delay) = delay {
+        let p = u64::try_from_val(e, &delay);
+        if let Ok(delay) = p {
+            update_min_delay(e, delay);
+        } else {
+            panic_with_error!(e, TimeLockError::InvalidParams);
+        }
+    } else {
+        panic_with_error!(e, TimeLockError::InvalidParams);
+    }
+}
+
+fn _update_target_min_delay(e: &Env, data: &Vec<Val>) {
+    let target = data.get(0);
+    let delay = data.get(1);
+    if let (Some(target), Some(delay)) = (target, delay) {
+        let target = Address::try_from_val(e, &target);
+        let delay = u64::try_from_val(e, &delay);
+        if let (Ok(target), Ok(delay)) = (target, delay) {
+            update_target_min_delay(e, &target, delay);
+        } else {
+            panic_with_error!(e, TimeLockError::InvalidParams);
+        }
+    } else {
+        panic_with_error!(e, TimeLockError::InvalidParams);
+    }
+}
+
+fn _update_fn_min_delay(e: &Env, data: &Vec<Val>) {
+    let target = data.get(0);
+    let fn_name = data.get(1);
+    let delay = data.get(2);
+    if let (Some(target), Some(fn_name), Some(delay)) = (target, fn_name, delay) {
+        let target = Address::try_from_val(e, &target);
+        let fn_name = Symbol::try_from_val(e, &fn_name);
+        let delay = u64::try_from_val(e, &delay);
+        if let (Ok(target), Ok(fn_name), Ok(delay)) = (target, fn_name, delay) {
+            update_fn_min_delay(e, &target, &fn_name, delay);
+        } else {
+            panic_with_error!(e, TimeLockError::InvalidParams);
+        }
+    } else {
+        panic_with_error!(e, TimeLockError::InvalidParams);
+    }
+}
+
+fn _update_grace_period(e: &Env, data: &Vec<Val>) {
+    let grace_period = data.get(0);
+    if let Some(grace_period) = grace_period {
+        let p = u64::try_from_val(e, &grace_period);
+        if let Ok(grace_period) = p {
+            update_grace_period(e, grace_period);
+        } else {
+            panic_with_error!(e, TimeLockError::InvalidParams);
+        }
+    } else {
+        panic_with_error!(e, TimeLockError::InvalidParams);
+    }
+}
+
+fn _update_ttl_margin(e: &Env, data: &Vec<Val>) {
+    let margin = data.get(0);
+    if let Some(margin) = margin {
+        let p = u32::try_from_val(e, &margin);
+        if let Ok(margin) = p {
+            update_ttl_margin(e, margin);
+        } else {
+            panic_with_error!(e, TimeLockError::InvalidParams);
+        }
+    } else {
+        panic_with_error!(e, TimeLockError::InvalidParams);
+    }
+}
+
+fn _update_proposal_threshold(e: &Env, data: &Vec<Val>) {
+    let threshold = data.get(0);
+    if let Some(threshold) = threshold {
+        let p = u32::try_from_val(e, &threshold);
+        if let Ok(threshold) = p {
+            update_proposal_threshold(e, threshold);
+        } else {
+            panic_with_error!(e, TimeLockError::InvalidParams);
+        }
+    } else {
+        panic_with_error!(e, TimeLockError::InvalidParams);
+    }
+}
+
+fn _update_open_execution(e: &Env, data: &Vec<Val>) {
+    let open = data.get(0);
+    if let Some(open) = open {
+        let p = bool::try_from_val(e, &open);
+        if let Ok(open) = p {
+            update_open_execution(e, open);
+        } else {
+            panic_with_error!(e, TimeLockError::InvalidParams);
+        }
+    } else {
+        panic_with_error!(e, TimeLockError::InvalidParams);
+    }
+}
+
+fn _upgrade(e: &Env, data: &Vec<Val>) {
+    let new_wasm_hash = data.get(0);
+    if let Some(new_wasm_hash) = new_wasm_hash {
+        let p = BytesN::<32>::try_from_val(e, &new_wasm_hash);
+        if let Ok(new_wasm_hash) = p {
+            upgrade(e, &new_wasm_hash);
+        } else {
+            panic_with_error!(e, TimeLockError::InvalidParams);
+        }
+    } else {
+        panic_with_error!(e, TimeLockError::InvalidParams);
+    }
+}
+
+fn _check_no_duplicates(e: &Env, accounts: &Vec<Address>) {
+    for (index, account) in accounts.iter().enumerate() {
+        if accounts.last_index_of(&account) != Some(index as u32) {
+            panic_with_error!(e, TimeLockError::InvalidParams);
+        }
+    }
+}
+
+/*
+ * Decodes a role argument of a scheduled call. Besides `Symbol` roles, the
+ * `RoleLabel` values taken by earlier versions of the role entry points are
+ * still accepted, so operations scheduled before the upgrade can execute.
+ */
+fn _role_from_val(e: &Env, role: &Val) -> Option<Symbol> {
+    if let Ok(role) = Symbol::try_from_val(e, role) {
+        return Some(role);
+    }
+    RoleLabel::try_from_val(e, role)
+        .ok()
+        .map(|label| label.to_symbol())
+}
+
+fn _set_role_admin(e: &Env, data: &Vec<Val>) {
+    let role = data.get(0);
+    let admin_role = data.get(1);
+    if let (Some(role), Some(admin_role)) = (role, admin_role) {
+        let role = _role_from_val(e, &role);
+        let admin_role = _role_from_val(e, &admin_role);
+        if let (Some(role), Some(admin_role)) = (role, admin_role) {
+            role_base::set_role_admin(e, &role, &admin_role, &ROLE_TTL);
         } else {
             panic_with_error!(e, TimeLockError::InvalidParams);
         }
@@ -316,12 +2143,13 @@ fn _update_role(e: &Env, data: &Vec<Val>, is_grand: bool) {
         let p = Address::try_from_val(e, &account);
         if let Ok(account) = p {
             if let Some(role) = role {
-                let p = RoleLabel::try_from_val(e, &role);
-                if let Ok(role) = p {
+                let p = _role_from_val(e, &role);
+                if let Some(role) = p {
+                    let sender = e.current_contract_address();
                     if is_grand {
-                        role_base::grant_role(e, &account, &role);
+                        role_base::grant_role(e, &account, &role, &sender, &ROLE_TTL);
                     } else {
-                        role_base::revoke_role(e, &account, &role);
+                        role_base::revoke_role(e, &account, &role, &sender, &ROLE_TTL);
                     }
                 } else {
                     panic_with_error!(e, TimeLockError::InvalidParams);
@@ -342,7 +2170,7 @@ fn _update_owner(e: &Env, data: &Vec<Val>) {
     if let Some(owner) = owner {
         let p = Address::try_from_val(e, &owner);
         if let Ok(owner) = p {
-            owner::set_owner(e, &owner);
+            owner::transfer_ownership(e, &owner);
         } else {
             panic_with_error!(e, TimeLockError::InvalidParams);
         }